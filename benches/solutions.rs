@@ -0,0 +1,55 @@
+use std::{hint::black_box, time::Duration};
+
+use advent_of_code_2015::{
+    generators,
+    solution::{self, Solution},
+};
+use criterion::{criterion_group, criterion_main, Criterion};
+
+/// Days whose solvers take long enough that 10 samples are plenty
+const SLOW_DAYS: [u32; 6] = [4, 6, 10, 20, 22, 24];
+
+/// `part1`, or `part1 whole_grid` for a named alternative
+fn variant(solution: &dyn Solution) -> String {
+    match solution.name() {
+        Some(name) => format!("part{} {}", solution.part(), name),
+        None => format!("part{}", solution.part()),
+    }
+}
+
+/// One group per day: its generator, then every registered variant on the parsed input
+fn solutions(c: &mut Criterion) {
+    for day in 1..=25 {
+        let input = generators::input(day, day.into()).unwrap();
+        let solutions: Vec<_> = solution::all()
+            .filter(|solution| solution.day() == day)
+            .collect();
+
+        let mut group = c.benchmark_group(format!("day{:02}", day));
+        if SLOW_DAYS.contains(&day) {
+            group
+                .sample_size(10)
+                .measurement_time(Duration::from_secs(20));
+        }
+
+        group.bench_function("generator", |b| {
+            b.iter(|| solutions[0].parse(black_box(&input)))
+        });
+
+        for solution in solutions {
+            let parsed = solution
+                .parse(&input)
+                .unwrap_or_else(|err| panic!("day{} generator: {}", day, err));
+            if let Err(err) = parsed.solve() {
+                panic!("day{} {}: {}", day, variant(solution), err);
+            }
+
+            group.bench_function(variant(solution), |b| b.iter(|| parsed.solve()));
+        }
+
+        group.finish();
+    }
+}
+
+criterion_group!(benches, solutions);
+criterion_main!(benches);
@@ -0,0 +1,174 @@
+use std::{collections::BTreeMap, error::Error, fmt, fs, io, path::Path};
+
+use toml::{Table, Value};
+
+use crate::solution::Answer;
+
+/// Name of the answer database, stored next to the inputs
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// Known correct answers, stored as
+///
+/// ```toml
+/// [day01]
+/// part1 = "138"
+/// part2 = "1771"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Answers {
+    answers: BTreeMap<(u32, u32), Answer>,
+}
+
+/// How an answer compares to the recorded one
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Verdict {
+    Correct,
+    Wrong { expected: Answer },
+    Unknown,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Correct => write!(f, "correct"),
+            Self::Wrong { expected } => write!(f, "WRONG, expected {}", expected),
+            Self::Unknown => write!(f, "no recorded answer"),
+        }
+    }
+}
+
+#[inline]
+fn number_after(key: &str, prefix: &str) -> Result<u32, Box<dyn Error>> {
+    Ok(key
+        .strip_prefix(prefix)
+        .ok_or_else(|| format!("{:?} does not start with {:?}", key, prefix))?
+        .parse()?)
+}
+
+impl Answers {
+    pub fn parse(text: &str) -> Result<Self, Box<dyn Error>> {
+        let table: Table = text.parse()?;
+        let mut answers = Self::default();
+        for (day_key, parts) in table {
+            let day = number_after(&day_key, "day")?;
+            let parts = parts
+                .as_table()
+                .ok_or_else(|| format!("[{}] is not a table", day_key))?;
+
+            for (part_key, answer) in parts {
+                let part = number_after(part_key, "part")?;
+                let answer = match answer {
+                    Value::String(answer) => answer.parse()?,
+                    Value::Integer(answer) => Answer::Integer(*answer),
+                    _ => {
+                        return Err(format!(
+                            "{}.{} must be a string or an integer",
+                            day_key, part_key
+                        )
+                        .into())
+                    }
+                };
+
+                answers.insert(day, part, answer);
+            }
+        }
+
+        Ok(answers)
+    }
+
+    /// A missing file is an empty database
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, Box<dyn Error>> {
+        let path = path.as_ref();
+        match fs::read_to_string(path) {
+            Ok(text) => {
+                Self::parse(&text).map_err(|err| format!("{}: {}", path.display(), err).into())
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(format!("{}: {}", path.display(), err).into()),
+        }
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        fs::write(path, self.to_string())
+    }
+
+    pub fn get(&self, day: u32, part: u32) -> Option<&Answer> {
+        self.answers.get(&(day, part))
+    }
+
+    pub fn insert(&mut self, day: u32, part: u32, answer: Answer) -> Option<Answer> {
+        self.answers.insert((day, part), answer)
+    }
+
+    pub fn len(&self) -> usize {
+        self.answers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.answers.is_empty()
+    }
+
+    pub fn check(&self, day: u32, part: u32, answer: &Answer) -> Verdict {
+        match self.get(day, part) {
+            None => Verdict::Unknown,
+            Some(expected) if expected == answer => Verdict::Correct,
+            Some(expected) => Verdict::Wrong {
+                expected: expected.clone(),
+            },
+        }
+    }
+}
+
+/// Every answer as a string, even numbers, so multi-line answers look like the others
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut table = Table::new();
+        for (&(day, part), answer) in &self.answers {
+            table
+                .entry(format!("day{:02}", day))
+                .or_insert_with(|| Value::Table(Table::new()))
+                .as_table_mut()
+                .expect("day entries are tables")
+                .insert(format!("part{}", part), Value::String(answer.to_string()));
+        }
+
+        write!(f, "{}", table)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = r#"[day01]
+part1 = 138
+part2 = "1771"
+
+[day11]
+part1 = "hepxxyzz"
+"#;
+
+    #[test]
+    fn parse_and_check() {
+        let answers = Answers::parse(EXAMPLE).unwrap();
+        assert_eq!(answers.len(), 3);
+        assert_eq!(answers.check(1, 1, &Answer::Integer(138)), Verdict::Correct);
+        assert_eq!(
+            answers.check(1, 2, &Answer::Integer(1772)),
+            Verdict::Wrong {
+                expected: Answer::Integer(1771)
+            }
+        );
+        assert_eq!(answers.check(11, 1, &"hepxxyzz".into()), Verdict::Correct);
+        assert_eq!(answers.check(11, 2, &"heqaabcc".into()), Verdict::Unknown);
+
+        assert!(Answers::parse("[day01]\npart1 = 1.5").is_err());
+        assert!(Answers::parse("[first]\npart1 = 1").is_err());
+    }
+
+    #[test]
+    fn round_trip() {
+        let answers = Answers::parse(EXAMPLE).unwrap();
+        assert_eq!(Answers::parse(&answers.to_string()).unwrap(), answers);
+    }
+}
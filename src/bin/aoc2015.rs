@@ -0,0 +1,374 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    env, fs,
+    io::{self, Read},
+    process::ExitCode,
+};
+
+use advent_of_code_2015::{
+    answers::{Answers, Verdict},
+    input::{self, InputProvider},
+    runner::{self, Report, ReportFormat, Run},
+    solution::{self, Answer, Solution},
+};
+
+const USAGE: &str = "Usage: aoc2015 --day N [--part P] [--name NAME] [--input FILE | -]
+       aoc2015 --check [--record] [--day N] [--part P] [--name NAME] [--answers FILE]
+       aoc2015 --all [--parallel] [--format FORMAT] [--day N] [--part P] [--name NAME]
+       aoc2015 --list [--day N] [--part P]
+
+Runs every registered solution of a day (or only those of a part, or only
+the alternative called NAME) on FILE, or on stdin when FILE is `-`.
+Without --input, reads dayNN.txt from $AOC2015_INPUT_DIR (default: inputs/2015).
+
+--check compares every answer with the ones recorded in answers.toml next to
+the inputs, and fails if any differs. Without --day, it checks every day that
+has an input. --record adds the answers that were not recorded yet.
+
+--all runs every solution that has an input, then prints their answers and
+timings as a table, or as FORMAT (table, json or markdown). --parallel runs
+them on the rayon thread pool.";
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct Args {
+    day: Option<u32>,
+    part: Option<u32>,
+    name: Option<String>,
+    input: Option<String>,
+    answers: Option<String>,
+    check: bool,
+    record: bool,
+    all: bool,
+    parallel: bool,
+    format: Option<ReportFormat>,
+    list: bool,
+    help: bool,
+}
+
+fn parse_args<I: IntoIterator<Item = String>>(args: I) -> Result<Args, String> {
+    let mut parsed = Args::default();
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("{} expects a value", arg))
+        };
+
+        match arg.as_str() {
+            "--day" | "-d" => {
+                let day = value()?;
+                parsed.day = Some(day.parse().map_err(|_| format!("Invalid day {:?}", day))?);
+            }
+            "--part" | "-p" => {
+                let part = value()?;
+                parsed.part = Some(
+                    part.parse()
+                        .map_err(|_| format!("Invalid part {:?}", part))?,
+                );
+            }
+            "--name" | "-n" => parsed.name = Some(value()?),
+            "--input" | "-i" => parsed.input = Some(value()?),
+            "--answers" | "-a" => parsed.answers = Some(value()?),
+            "--check" | "-c" => parsed.check = true,
+            "--record" | "-r" => parsed.record = true,
+            "--all" | "-A" => parsed.all = true,
+            "--parallel" | "-j" => parsed.parallel = true,
+            "--format" | "-f" => parsed.format = Some(value()?.parse()?),
+            "--list" | "-l" => parsed.list = true,
+            "--help" | "-h" => parsed.help = true,
+            _ => return Err(format!("Unknown argument {:?}", arg)),
+        }
+    }
+
+    let every_day = parsed.list || parsed.help || parsed.check || parsed.record || parsed.all;
+    if parsed.day.is_none() && !every_day {
+        return Err("--day is required".to_owned());
+    }
+
+    if parsed.day.is_none() && parsed.input.is_some() {
+        return Err("--input needs --day".to_owned());
+    }
+
+    if parsed.all && (parsed.check || parsed.record) {
+        return Err("--all cannot be combined with --check or --record".to_owned());
+    }
+
+    if !parsed.all && (parsed.parallel || parsed.format.is_some()) {
+        return Err("--parallel and --format need --all".to_owned());
+    }
+
+    Ok(parsed)
+}
+
+impl Args {
+    fn matches(&self, solution: &dyn Solution) -> bool {
+        self.day.is_none_or(|day| solution.day() == day)
+            && self.part.is_none_or(|part| solution.part() == part)
+            && self
+                .name
+                .as_deref()
+                .is_none_or(|name| solution.name() == Some(name))
+    }
+}
+
+/// `day6 part1 whole_grid`, the way `cargo aoc` names its runners
+fn label(solution: &dyn Solution) -> String {
+    match solution.name() {
+        Some(name) => format!("day{} part{} {}", solution.day(), solution.part(), name),
+        None => format!("day{} part{}", solution.day(), solution.part()),
+    }
+}
+
+/// `-` reads stdin, no path looks for the day in the input directory
+fn read_input(provider: &InputProvider, day: u32, path: Option<&str>) -> io::Result<String> {
+    let input = match path {
+        None => return provider.load(day),
+        Some("-") => {
+            let mut input = String::new();
+            io::stdin().read_to_string(&mut input)?;
+            input
+        }
+        Some(path) => fs::read_to_string(path)?,
+    };
+
+    Ok(input::normalize(&input).into_owned())
+}
+
+/// Prints the answer and timings, or the reason there is no answer
+fn run(solution: &dyn Solution, input: &str) -> Option<Answer> {
+    let run = Run::new(solution, input);
+    match run.answer {
+        Ok(answer) => {
+            println!(
+                "{}: {}\n\tgenerator: {:?},\n\trunner: {:?}",
+                label(solution),
+                answer,
+                run.parse_time,
+                run.solve_time
+            );
+            Some(answer)
+        }
+        Err(err) => {
+            eprintln!("{}: {}", label(solution), err);
+            None
+        }
+    }
+}
+
+/// `--all`: runs every solution that has an input, then prints one report
+fn run_all(args: &Args, solutions: &[&dyn Solution], provider: &InputProvider) -> ExitCode {
+    let days: BTreeSet<_> = solutions.iter().map(|solution| solution.day()).collect();
+    let mut inputs = BTreeMap::new();
+    let mut failed = false;
+    for day in days {
+        match read_input(provider, day, args.input.as_deref()) {
+            Ok(input) => {
+                inputs.insert(day, input);
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound && args.day.is_none() => {}
+            Err(err) => {
+                eprintln!("day{}: could not read the input: {}", day, err);
+                failed = true;
+            }
+        }
+    }
+
+    let jobs: Vec<_> = solutions
+        .iter()
+        .filter_map(|&solution| {
+            inputs
+                .get(&solution.day())
+                .map(|input| (solution, input.as_str()))
+        })
+        .collect();
+
+    let runs = runner::run_all(&jobs, args.parallel);
+    print!(
+        "{}",
+        Report {
+            runs: &runs,
+            format: args.format.unwrap_or_default(),
+        }
+    );
+
+    if failed || runs.iter().any(|run| run.answer.is_err()) {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct Tally {
+    correct: usize,
+    wrong: usize,
+    unknown: usize,
+    failed: usize,
+}
+
+fn main() -> ExitCode {
+    let args = match parse_args(env::args().skip(1)) {
+        Ok(args) => args,
+        Err(err) => {
+            eprintln!("{}\n\n{}", err, USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if args.help {
+        println!("{}", USAGE);
+        return ExitCode::SUCCESS;
+    }
+
+    let solutions: Vec<_> = solution::all()
+        .filter(|&solution| args.matches(solution))
+        .collect();
+
+    if args.list {
+        for solution in solutions {
+            println!("{}", label(solution));
+        }
+
+        return ExitCode::SUCCESS;
+    }
+
+    if solutions.is_empty() {
+        eprintln!("No solution registered for these arguments");
+        return ExitCode::FAILURE;
+    }
+
+    let provider = InputProvider::from_env();
+    if args.all {
+        return run_all(&args, &solutions, &provider);
+    }
+
+    let answers_path = args
+        .answers
+        .as_ref()
+        .map_or_else(|| provider.answers_path(), Into::into);
+    let mut answers = if args.check || args.record {
+        match Answers::load(&answers_path) {
+            Ok(answers) => answers,
+            Err(err) => {
+                eprintln!("Could not read the answers: {}", err);
+                return ExitCode::FAILURE;
+            }
+        }
+    } else {
+        Answers::default()
+    };
+
+    let mut inputs = BTreeMap::new();
+    let mut tally = Tally::default();
+    let mut recorded = 0;
+    for solution in solutions {
+        let day = solution.day();
+        let input = inputs
+            .entry(day)
+            .or_insert_with(|| read_input(&provider, day, args.input.as_deref()));
+
+        let input = match input {
+            Ok(input) => input,
+            // checking every day only covers the days we have an input for
+            Err(err) if err.kind() == io::ErrorKind::NotFound && args.day.is_none() => continue,
+            Err(err) => {
+                eprintln!("{}: could not read the input: {}", label(solution), err);
+                tally.failed += 1;
+                continue;
+            }
+        };
+
+        let Some(answer) = run(solution, input) else {
+            tally.failed += 1;
+            continue;
+        };
+
+        if args.check || args.record {
+            let verdict = answers.check(day, solution.part(), &answer);
+            println!("\tcheck: {}", verdict);
+            match verdict {
+                Verdict::Correct => tally.correct += 1,
+                Verdict::Wrong { .. } => tally.wrong += 1,
+                Verdict::Unknown => {
+                    tally.unknown += 1;
+                    if args.record {
+                        answers.insert(day, solution.part(), answer);
+                        recorded += 1;
+                    }
+                }
+            }
+        }
+
+        println!();
+    }
+
+    if recorded > 0 {
+        if let Err(err) = answers.save(&answers_path) {
+            eprintln!("Could not save {}: {}", answers_path.display(), err);
+            return ExitCode::FAILURE;
+        }
+
+        println!(
+            "Recorded {} new answers in {}",
+            recorded,
+            answers_path.display()
+        );
+    }
+
+    if args.check {
+        println!(
+            "{} correct, {} wrong, {} without a recorded answer, {} failed",
+            tally.correct, tally.wrong, tally.unknown, tally.failed
+        );
+    }
+
+    if tally.wrong == 0 && tally.failed == 0 {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(line: &str) -> Result<Args, String> {
+        parse_args(line.split_whitespace().map(str::to_owned))
+    }
+
+    #[test]
+    fn arguments() {
+        assert_eq!(
+            args("--day 6 --part 1 --input -").unwrap(),
+            Args {
+                day: Some(6),
+                part: Some(1),
+                input: Some("-".to_owned()),
+                ..Args::default()
+            }
+        );
+        assert!(args("--list").unwrap().list);
+        assert!(args("--check").unwrap().check);
+        assert!(args("--part 1").is_err());
+        assert!(args("--check --input day07.txt").is_err());
+        assert!(args("--day six").is_err());
+        assert!(args("--day 6 --input").is_err());
+
+        let all = args("--all --parallel --format md --part 2").unwrap();
+        assert!(all.all && all.parallel);
+        assert_eq!(all.format, Some(ReportFormat::Markdown));
+        assert!(args("--all --format csv").is_err());
+        assert!(args("--day 1 --parallel").is_err());
+        assert!(args("--all --check").is_err());
+    }
+
+    #[test]
+    fn alternatives() {
+        let day4: Vec<_> = solution::all()
+            .filter(|&solution| args("--day 4").unwrap().matches(solution))
+            .map(label)
+            .collect();
+        assert_eq!(day4, ["day4 part1 brute", "day4 part2 brute"]);
+    }
+}
@@ -1,7 +1,9 @@
 use aoc_runner_derive::aoc;
 
+use crate::solution::{raw_input, Solution, Solver};
+
 #[aoc(day1, part1)]
-fn part1(input: &[u8]) -> i64 {
+pub fn part1(input: &[u8]) -> i64 {
     let mut count = 0;
     for &c in input {
         if c == b'(' {
@@ -15,7 +17,7 @@ fn part1(input: &[u8]) -> i64 {
 }
 
 #[aoc(day1, part2)]
-fn part2(input: &[u8]) -> i64 {
+pub fn part2(input: &[u8]) -> i64 {
     let mut count = 0;
     for (i, &c) in input.iter().enumerate() {
         if c == b'(' {
@@ -32,9 +34,17 @@ fn part2(input: &[u8]) -> i64 {
     unreachable!()
 }
 
+pub const SOLUTIONS: &[&dyn Solution] = &[
+    &Solver::new(1, 1, raw_input, |input| part1(input.as_bytes())),
+    &Solver::new(1, 2, raw_input, |input| part2(input.as_bytes())),
+];
+
 #[cfg(test)]
 mod tests {
+    use proptest::prelude::*;
+
     use super::*;
+    use crate::generators;
 
     const EXAMPLES: [(&[u8], i64); 9] = [
         (b"(())", 0),
@@ -63,4 +73,21 @@ mod tests {
             assert_eq!(part2(input), answer);
         }
     }
+
+    proptest! {
+        #[test]
+        fn part1_counts_parentheses(input in "[()]{0,200}") {
+            let up = input.matches('(').count() as i64;
+            let down = input.matches(')').count() as i64;
+            prop_assert_eq!(part1(input.as_bytes()), up - down);
+        }
+
+        #[test]
+        fn part2_first_basement_visit(seed in any::<u64>(), len in 0..500usize) {
+            let input = generators::day01(&mut generators::seeded(seed), len);
+            let position = part2(input.as_bytes()) as usize;
+            prop_assert_eq!(part1(&input.as_bytes()[..position]), -1);
+            prop_assert!((0..position).all(|i| part1(&input.as_bytes()[..i]) >= 0));
+        }
+    }
 }
@@ -1,14 +1,22 @@
-use std::{error, str::FromStr};
+use std::{borrow::Borrow, io::BufRead, str::FromStr};
+
+use itertools::process_results;
+
+use crate::{
+    error::{parse_lines, read_lines, Line, ParseError, ReadError},
+    solution::{Solution, Solver},
+};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-struct Gift {
-    height: u32,
-    width: u32,
-    length: u32,
+pub struct Gift {
+    pub height: u32,
+    pub width: u32,
+    pub length: u32,
 }
 
 impl Gift {
-    pub(crate) fn calculate_wrapping_required(&self) -> u64 {
+    /// Paper needed: the surface of the box plus the area of its smallest side
+    pub fn calculate_wrapping_required(&self) -> u64 {
         let sides = [
             (self.length as u64 * self.width as u64),
             (self.width as u64 * self.height as u64),
@@ -17,11 +25,13 @@ impl Gift {
         (2 * sides.iter().sum::<u64>()) + sides.into_iter().min().unwrap()
     }
 
-    pub(crate) const fn ribbon_for_bow(&self) -> u64 {
+    /// Ribbon needed for the bow: the volume of the box
+    pub const fn ribbon_for_bow(&self) -> u64 {
         self.height as u64 * self.length as u64 * self.width as u64
     }
 
-    pub(crate) const fn ribbon_required(&self) -> u64 {
+    /// Ribbon needed around the box (smallest perimeter), bow included
+    pub const fn ribbon_required(&self) -> u64 {
         let (shortest_1, largest) = if self.height < self.width {
             (self.height, self.width)
         } else {
@@ -39,57 +49,80 @@ impl Gift {
 }
 
 impl FromStr for Gift {
-    type Err = Box<dyn error::Error>;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut splits = s.split('x');
+        let line = Line::new(s, 1);
+        let (height, rest) = line.split_once(s, "x")?;
+        let (width, length) = line.split_once(rest, "x")?;
         Ok(Self {
-            height: splits
-                .next()
-                .ok_or_else(|| format!("{:?} has -1 parts when split on 'x'?", s))?
-                .parse()?,
-            width: splits
-                .next()
-                .ok_or_else(|| format!("{:?} could not be split on 'x'", s))?
-                .parse()?,
-            length: splits
-                .next()
-                .ok_or_else(|| format!("{:?} could not be split twice on 'x'?", s))?
-                .parse()?,
+            height: line.number(height)?,
+            width: line.number(width)?,
+            length: line.number(length)?,
         })
     }
 }
 
 #[aoc_generator(day2)]
-fn parser(input: &str) -> Result<Vec<Gift>, Box<dyn error::Error>> {
-    input
-        .lines()
-        .filter_map(|line| {
-            let line = line.trim();
-            if line.is_empty() {
-                None
-            } else {
-                Some(line.parse())
-            }
-        })
-        .collect()
+pub fn parser(input: &str) -> Result<Vec<Gift>, ParseError> {
+    parse_lines(input)
 }
 
-#[aoc(day2, part1)]
-fn part1(gifts: &[Gift]) -> u64 {
+/// Gifts read one line at a time, for inputs too large to be loaded at once
+pub fn read_gifts<R: BufRead>(reader: R) -> impl Iterator<Item = Result<Gift, ReadError>> {
+    read_lines(reader)
+}
+
+pub fn total_wrapping<I>(gifts: I) -> u64
+where
+    I: IntoIterator,
+    I::Item: Borrow<Gift>,
+{
     gifts
-        .iter()
-        .map(|gift| gift.calculate_wrapping_required())
+        .into_iter()
+        .map(|gift| gift.borrow().calculate_wrapping_required())
         .sum()
 }
 
+pub fn total_ribbon<I>(gifts: I) -> u64
+where
+    I: IntoIterator,
+    I::Item: Borrow<Gift>,
+{
+    gifts
+        .into_iter()
+        .map(|gift| gift.borrow().ribbon_required())
+        .sum()
+}
+
+#[aoc(day2, part1)]
+pub fn part1(gifts: &[Gift]) -> u64 {
+    total_wrapping(gifts)
+}
+
 #[aoc(day2, part2)]
-fn part2(gifts: &[Gift]) -> u64 {
-    gifts.iter().map(|gift| gift.ribbon_required()).sum()
+pub fn part2(gifts: &[Gift]) -> u64 {
+    total_ribbon(gifts)
+}
+
+pub fn part1_streaming<R: BufRead>(reader: R) -> Result<u64, ReadError> {
+    process_results(read_gifts(reader), |gifts| total_wrapping(gifts))
 }
 
+pub fn part2_streaming<R: BufRead>(reader: R) -> Result<u64, ReadError> {
+    process_results(read_gifts(reader), |gifts| total_ribbon(gifts))
+}
+
+pub const SOLUTIONS: &[&dyn Solution] = &[
+    &Solver::new(2, 1, parser, |gifts| part1(gifts)),
+    &Solver::new(2, 2, parser, |gifts| part2(gifts)),
+];
+
 #[cfg(test)]
 mod tests {
+    use itertools::Itertools;
+    use proptest::prelude::*;
+
     use super::*;
 
     #[test]
@@ -111,4 +144,44 @@ mod tests {
     fn part2_example_2() {
         assert_eq!(part2(&parser("1x1x10").unwrap()), 14);
     }
+
+    #[test]
+    fn streaming() {
+        assert_eq!(
+            part1_streaming("2x3x4\n1x1x10\n".as_bytes()).unwrap(),
+            58 + 43
+        );
+        assert_eq!(
+            part2_streaming("2x3x4\r\n1x1x10\r\n".as_bytes()).unwrap(),
+            34 + 14
+        );
+
+        let err = part1_streaming("2x3x4\n\n1x1\n".as_bytes()).unwrap_err();
+        assert_eq!(err.to_string(), r#"line 3, column 3: expected "x""#);
+    }
+
+    proptest! {
+        #[test]
+        fn dimension_order_does_not_matter(dimensions in prop::array::uniform3(1u32..10_000)) {
+            let [height, width, length] = dimensions;
+            let gift = Gift { height, width, length };
+            for order in dimensions.into_iter().permutations(3) {
+                let permuted = Gift { height: order[0], width: order[1], length: order[2] };
+                prop_assert_eq!(permuted.ribbon_required(), gift.ribbon_required());
+                prop_assert_eq!(
+                    permuted.calculate_wrapping_required(),
+                    gift.calculate_wrapping_required()
+                );
+            }
+        }
+
+        #[test]
+        fn ribbon_wraps_the_smallest_face(mut dimensions in prop::array::uniform3(1u32..10_000)) {
+            let [height, width, length] = dimensions;
+            let gift = Gift { height, width, length };
+            dimensions.sort_unstable();
+            let [a, b, c] = dimensions.map(u64::from);
+            prop_assert_eq!(gift.ribbon_required(), 2 * (a + b) + a * b * c);
+        }
+    }
 }
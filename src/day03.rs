@@ -1,7 +1,9 @@
 use fnv::FnvHashSet;
 
+use crate::solution::{raw_input, Solution, Solver};
+
 #[aoc(day3, part1)]
-fn part1(input: &str) -> u64 {
+pub fn part1(input: &str) -> u64 {
     let mut delivered = FnvHashSet::default();
     let mut current_row: i32 = 0;
     let mut current_column: i32 = 0;
@@ -24,7 +26,7 @@ fn part1(input: &str) -> u64 {
 }
 
 #[aoc(day3, part2)]
-fn part2(input: &str) -> u64 {
+pub fn part2(input: &str) -> u64 {
     let mut delivered = FnvHashSet::default();
     let mut current_row: i32 = 0;
     let mut current_column: i32 = 0;
@@ -60,6 +62,11 @@ fn part2(input: &str) -> u64 {
     delivered.len() as u64
 }
 
+pub const SOLUTIONS: &[&dyn Solution] = &[
+    &Solver::new(3, 1, raw_input, |input| part1(input)),
+    &Solver::new(3, 2, raw_input, |input| part2(input)),
+];
+
 #[cfg(test)]
 mod tests {
     use super::*;
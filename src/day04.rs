@@ -1,7 +1,9 @@
 use md5;
 
+use crate::solution::{raw_input, Solution, Solver};
+
 #[aoc(day4, part1, brute)]
-fn part1_brute(input: &str) -> u64 {
+pub fn part1_brute(input: &str) -> u64 {
     (1..)
         .find(|num| {
             let digest = md5::compute(format!("{}{}", input, num)).0;
@@ -11,7 +13,7 @@ fn part1_brute(input: &str) -> u64 {
 }
 
 #[aoc(day4, part2, brute)]
-fn part2_brute(input: &str) -> u64 {
+pub fn part2_brute(input: &str) -> u64 {
     (1..)
         .find(|num| {
             let digest = md5::compute(format!("{}{}", input, num)).0;
@@ -20,6 +22,11 @@ fn part2_brute(input: &str) -> u64 {
         .expect("Could not find any value matching predicate")
 }
 
+pub const SOLUTIONS: &[&dyn Solution] = &[
+    &Solver::new(4, 1, raw_input, |input| part1_brute(input)).named("brute"),
+    &Solver::new(4, 2, raw_input, |input| part2_brute(input)).named("brute"),
+];
+
 #[cfg(test)]
 mod tests {
     use super::*;
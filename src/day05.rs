@@ -1,4 +1,9 @@
+use std::io::{self, BufRead};
+
 use fnv::FnvHashSet;
+use itertools::process_results;
+
+use crate::solution::{raw_input, Solution, Solver};
 
 const VOWELS: [char; 5] = ['a', 'e', 'i', 'o', 'u'];
 
@@ -61,16 +66,53 @@ fn is_nice_part2(s: &str) -> bool {
     first_condition && second_condition
 }
 
+pub fn count_nice<I>(strings: I) -> u64
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    strings
+        .into_iter()
+        .filter(|string| is_nice(string.as_ref()))
+        .count() as u64
+}
+
+pub fn count_nice_part2<I>(strings: I) -> u64
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    strings
+        .into_iter()
+        .filter(|string| is_nice_part2(string.as_ref()))
+        .count() as u64
+}
+
 #[aoc(day5, part1)]
-fn part1(input: &str) -> u64 {
-    input.lines().filter(|&line| is_nice(line)).count() as u64
+pub fn part1(input: &str) -> u64 {
+    count_nice(input.lines())
 }
 
 #[aoc(day5, part2)]
-fn part2(input: &str) -> u64 {
-    input.lines().filter(|&line| is_nice_part2(line)).count() as u64
+pub fn part2(input: &str) -> u64 {
+    count_nice_part2(input.lines())
+}
+
+/// Same as `part1`, reading one string at a time
+pub fn part1_streaming<R: BufRead>(reader: R) -> io::Result<u64> {
+    process_results(reader.lines(), |strings| count_nice(strings))
+}
+
+/// Same as `part2`, reading one string at a time
+pub fn part2_streaming<R: BufRead>(reader: R) -> io::Result<u64> {
+    process_results(reader.lines(), |strings| count_nice_part2(strings))
 }
 
+pub const SOLUTIONS: &[&dyn Solution] = &[
+    &Solver::new(5, 1, raw_input, |input| part1(input)),
+    &Solver::new(5, 2, raw_input, |input| part2(input)),
+];
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -119,4 +161,11 @@ mod tests {
     fn part2_example4() {
         assert_eq!(part2("ieodomkazucvgmuy"), 0);
     }
+
+    #[test]
+    fn streaming() {
+        let input = "ugknbfddgicrmopn\naaa\njchzalrnumimnmhp\nqjhvhtzxzqqjkmpb\nxxyxx\n";
+        assert_eq!(part1_streaming(input.as_bytes()).unwrap(), part1(input));
+        assert_eq!(part2_streaming(input.as_bytes()).unwrap(), part2(input));
+    }
 }
@@ -1,137 +1,180 @@
-use std::{error::Error, str::FromStr};
+use std::{borrow::Borrow, io::BufRead, str::FromStr};
+
+use itertools::process_results;
+
+use crate::{
+    error::{parse_lines, read_lines, Line, ParseError, ReadError},
+    grid::Grid,
+    solution::{Solution, Solver},
+};
+
+const GRID_SIZE: usize = 1000;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-enum InstructionKind {
+pub enum InstructionKind {
     TurnOn,
     TurnOff,
     Toggle,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-struct Instruction {
-    kind: InstructionKind,
-    start_pos: (u32, u32),
-    end_pos: (u32, u32),
+pub struct Instruction {
+    pub kind: InstructionKind,
+    pub start_pos: (u32, u32),
+    pub end_pos: (u32, u32),
 }
 
 #[inline]
-fn parse_position(s: &str) -> Result<(u32, u32), Box<dyn Error>> {
-    let Some((first, second)) = s.split_once(',') else {
-        return Err(format!(r#"{:?} did not contain ','"#, s).into());
-    };
+fn parse_position(line: &Line, s: &str) -> Result<(u32, u32), ParseError> {
+    let (first, second) = line.split_once(s, ",")?;
+    let position = (line.number(first)?, line.number(second)?);
+    if position.0 as usize >= GRID_SIZE || position.1 as usize >= GRID_SIZE {
+        return Err(line.invalid(
+            s.trim(),
+            format!("{:?} is outside of the {}x{} grid", s, GRID_SIZE, GRID_SIZE),
+        ));
+    }
 
-    Ok((first.parse()?, second.parse()?))
+    Ok(position)
 }
 
-#[inline]
-fn parse_range(s: &str) -> Result<((u32, u32), (u32, u32)), Box<dyn Error>> {
-    let Some((first, second)) = s.split_once(" through ") else {
-        return Err(format!(r#"{:?} did not contain " through ""#, s).into());
-    };
+type Range = ((u32, u32), (u32, u32));
 
-    Ok((parse_position(first)?, parse_position(second)?))
+#[inline]
+fn parse_range(line: &Line, s: &str) -> Result<Range, ParseError> {
+    let (first, second) = line.split_once(s, " through ")?;
+    Ok((parse_position(line, first)?, parse_position(line, second)?))
 }
 
 impl FromStr for Instruction {
-    type Err = Box<dyn Error>;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let line = Line::new(s, 1);
         if let Some(rest) = s.strip_prefix("turn on ") {
-            let (start_pos, end_pos) = parse_range(rest)?;
+            let (start_pos, end_pos) = parse_range(&line, rest)?;
             Ok(Self {
                 kind: InstructionKind::TurnOn,
                 start_pos,
                 end_pos,
             })
         } else if let Some(rest) = s.strip_prefix("turn off ") {
-            let (start_pos, end_pos) = parse_range(rest)?;
+            let (start_pos, end_pos) = parse_range(&line, rest)?;
             Ok(Self {
                 kind: InstructionKind::TurnOff,
                 start_pos,
                 end_pos,
             })
         } else if let Some(rest) = s.strip_prefix("toggle ") {
-            let (start_pos, end_pos) = parse_range(rest)?;
+            let (start_pos, end_pos) = parse_range(&line, rest)?;
             Ok(Self {
                 kind: InstructionKind::Toggle,
                 start_pos,
                 end_pos,
             })
         } else {
-            Err(format!(
-                r#"{:?} did not start with any of "turn on ", "turn off " or "toggle ""#,
-                s
-            )
-            .into())
+            Err(line.expected(s, r#"one of "turn on ", "turn off " or "toggle ""#))
         }
     }
 }
 
 #[aoc_generator(day6)]
-fn parse(input: &str) -> Result<Vec<Instruction>, Box<dyn Error>> {
-    input.lines().map(|line| line.parse()).collect()
+pub fn parse(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    parse_lines(input)
 }
 
-#[aoc(day6, part1, whole_grid)]
-fn part1_whole_grid(instructions: &[Instruction]) -> u64 {
-    let mut grid = (0..1000)
-        .map(|_| (0..1000).map(|_| false).collect::<Box<[_]>>())
-        .collect::<Box<_>>();
+/// Instructions read one line at a time, for inputs too large to be loaded at once
+pub fn read_instructions<R: BufRead>(
+    reader: R,
+) -> impl Iterator<Item = Result<Instruction, ReadError>> {
+    read_lines(reader)
+}
+
+/// Follows every instruction on the whole grid, then counts the lights that are on
+pub fn lights_on<I>(instructions: I) -> u64
+where
+    I: IntoIterator,
+    I::Item: Borrow<Instruction>,
+{
+    let mut grid = Grid::<bool>::new(GRID_SIZE, GRID_SIZE);
 
     for instr in instructions {
+        let instr = instr.borrow();
         // dbg!(instr);
         for row in instr.start_pos.0..=instr.end_pos.0 {
             for col in instr.start_pos.1..=instr.end_pos.1 {
+                let light = &mut grid[(row as usize, col as usize)];
                 match instr.kind {
-                    InstructionKind::TurnOn => grid[row as usize][col as usize] = true,
-                    InstructionKind::TurnOff => grid[row as usize][col as usize] = false,
-                    InstructionKind::Toggle => {
-                        grid[row as usize][col as usize] = !grid[row as usize][col as usize]
-                    }
+                    InstructionKind::TurnOn => *light = true,
+                    InstructionKind::TurnOff => *light = false,
+                    InstructionKind::Toggle => *light = !*light,
                 }
             }
         }
     }
 
-    grid.iter()
-        .flat_map(|row| row.iter())
-        .filter(|&&is_on| is_on)
-        .count() as u64
+    grid.iter().filter(|&&is_on| is_on).count() as u64
 }
 
-#[aoc(day6, part2, whole_grid)]
-fn part2_whole_grid(instructions: &[Instruction]) -> u64 {
-    let mut grid = (0..1000)
-        .map(|_| (0..1000).map(|_| 0_u32).collect::<Box<[_]>>())
-        .collect::<Box<_>>();
+/// Follows every instruction on the whole grid, then adds up the brightness of the lights
+pub fn total_brightness<I>(instructions: I) -> u64
+where
+    I: IntoIterator,
+    I::Item: Borrow<Instruction>,
+{
+    let mut grid = Grid::<u32>::new(GRID_SIZE, GRID_SIZE);
 
     for instr in instructions {
+        let instr = instr.borrow();
         // dbg!(instr);
         for row in instr.start_pos.0..=instr.end_pos.0 {
             for col in instr.start_pos.1..=instr.end_pos.1 {
+                let light = &mut grid[(row as usize, col as usize)];
                 match instr.kind {
-                    InstructionKind::TurnOn => grid[row as usize][col as usize] += 1,
-                    InstructionKind::TurnOff => {
-                        if let Some(new) = grid[row as usize][col as usize].checked_sub(1) {
-                            grid[row as usize][col as usize] = new;
-                        }
-                    }
-                    InstructionKind::Toggle => grid[row as usize][col as usize] += 2,
+                    InstructionKind::TurnOn => *light += 1,
+                    InstructionKind::TurnOff => *light = light.saturating_sub(1),
+                    InstructionKind::Toggle => *light += 2,
                 }
             }
         }
     }
 
-    grid.iter()
-        .flat_map(|row| row.iter())
-        .cloned()
-        .map(u64::from)
-        .sum()
+    grid.iter().cloned().map(u64::from).sum()
+}
+
+#[aoc(day6, part1, whole_grid)]
+pub fn part1_whole_grid(instructions: &[Instruction]) -> u64 {
+    lights_on(instructions)
+}
+
+#[aoc(day6, part2, whole_grid)]
+pub fn part2_whole_grid(instructions: &[Instruction]) -> u64 {
+    total_brightness(instructions)
 }
 
+pub fn part1_streaming<R: BufRead>(reader: R) -> Result<u64, ReadError> {
+    process_results(read_instructions(reader), |instructions| {
+        lights_on(instructions)
+    })
+}
+
+pub fn part2_streaming<R: BufRead>(reader: R) -> Result<u64, ReadError> {
+    process_results(read_instructions(reader), |instructions| {
+        total_brightness(instructions)
+    })
+}
+
+pub const SOLUTIONS: &[&dyn Solution] = &[
+    &Solver::new(6, 1, parse, |instructions| part1_whole_grid(instructions)).named("whole_grid"),
+    &Solver::new(6, 2, parse, |instructions| part2_whole_grid(instructions)).named("whole_grid"),
+];
+
 #[cfg(test)]
 mod tests {
+    use proptest::prelude::*;
+
     use super::*;
+    use crate::generators;
 
     #[test]
     fn part1_whole_grid_example1() {
@@ -159,6 +202,16 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_errors() {
+        let err = parse("turn on 0,0 through 999,999\ntoggle 0,0 throught 999,0").unwrap_err();
+        assert_eq!(err.position().line, 2);
+        assert_eq!(err.position().column, 8);
+
+        let err = parse("turn off 0,0 through 1000,5").unwrap_err();
+        assert_eq!(err.position().column, 22);
+    }
+
     #[test]
     fn part2_whole_grid_example1() {
         assert_eq!(
@@ -174,4 +227,51 @@ mod tests {
             2_000_000
         );
     }
+
+    #[test]
+    fn streaming() {
+        let input = "turn on 0,0 through 999,999\ntoggle 0,0 through 999,0\nturn off 499,499 through 500,500\n";
+        assert_eq!(
+            part1_streaming(input.as_bytes()).unwrap(),
+            1_000_000 - 1_000 - 4
+        );
+        assert_eq!(
+            part2_streaming(input.as_bytes()).unwrap(),
+            1_000_000 + 2_000 - 4
+        );
+
+        let err = part1_streaming("toggle 0,0 throught 999,0".as_bytes()).unwrap_err();
+        assert!(matches!(err, ReadError::Parse(err) if err.position().column == 8));
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(16))]
+
+        /// A light that is on was last turned on or toggled, so it is at least as bright as 1
+        #[test]
+        fn part1_at_most_part2(seed in any::<u64>(), len in 1..6usize) {
+            let instructions = parse(&generators::day06(&mut generators::seeded(seed), len)).unwrap();
+            prop_assert!(part1_whole_grid(&instructions) <= part2_whole_grid(&instructions));
+        }
+
+        #[test]
+        fn toggling_twice_changes_nothing_in_part1(seed in any::<u64>(), len in 1..6usize) {
+            let mut instructions = parse(&generators::day06(&mut generators::seeded(seed), len)).unwrap();
+            let lit = part1_whole_grid(&instructions);
+            let brightness = part2_whole_grid(&instructions);
+
+            let toggle = Instruction {
+                kind: InstructionKind::Toggle,
+                ..instructions[0].clone()
+            };
+            let (rows, cols) = (
+                (toggle.end_pos.0 - toggle.start_pos.0 + 1) as u64,
+                (toggle.end_pos.1 - toggle.start_pos.1 + 1) as u64,
+            );
+            instructions.extend([toggle.clone(), toggle]);
+
+            prop_assert_eq!(part1_whole_grid(&instructions), lit);
+            prop_assert_eq!(part2_whole_grid(&instructions), brightness + 4 * rows * cols);
+        }
+    }
 }
@@ -1,3 +1,7 @@
+use crate::{
+    error::{lines, Line, ParseError},
+    solution::{Solution, Solver},
+};
 use core::fmt;
 use fnv::FnvHashMap;
 use itertools::Itertools;
@@ -9,7 +13,7 @@ use std::{
 };
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-enum LogicGate {
+pub enum LogicGate {
     And(String, String),
     Or(String, String),
     Not(String),
@@ -19,6 +23,17 @@ enum LogicGate {
 }
 
 impl LogicGate {
+    /// The wires and signals read by the gate, in order
+    pub fn operands(&self) -> Vec<&str> {
+        match self {
+            Self::And(ident1, ident2) | Self::Or(ident1, ident2) => vec![ident1, ident2],
+            Self::Not(ident)
+            | Self::LeftShift(ident, _)
+            | Self::RightShift(ident, _)
+            | Self::Constant(ident) => vec![ident],
+        }
+    }
+
     #[inline]
     fn get_wire_value(&self, wires: &FnvHashMap<String, Wire>) -> u16 {
         match self {
@@ -67,31 +82,51 @@ impl LogicGate {
     }
 }
 
+/// A wire name or a constant signal, validated once every wire is known
+fn operand(line: &Line, part: &str) -> Result<String, ParseError> {
+    let operand = part.trim();
+    if operand.is_empty() || operand.contains(char::is_whitespace) {
+        return Err(line.expected(operand, "a wire or a signal"));
+    }
+
+    Ok(operand.to_owned())
+}
+
+fn shift_amount(line: &Line, part: &str) -> Result<u8, ParseError> {
+    let shift = line.number(part)?;
+    if shift >= u16::BITS as u8 {
+        return Err(line.invalid(
+            part.trim(),
+            format!("cannot shift a 16 bit signal by {}", shift),
+        ));
+    }
+
+    Ok(shift)
+}
+
 impl FromStr for LogicGate {
-    type Err = Box<dyn Error>;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if let Some(ident) = s.strip_prefix("NOT") {
-            Ok(Self::Not(ident.trim().to_owned()))
+        let line = Line::new(s, 1);
+        if let Some(ident) = s.trim_start().strip_prefix("NOT") {
+            Ok(Self::Not(operand(&line, ident)?))
         } else if let Some((ident1, ident2)) = s.split_once("AND") {
-            Ok(Self::And(
-                ident1.trim().to_owned(),
-                ident2.trim().to_owned(),
-            ))
+            Ok(Self::And(operand(&line, ident1)?, operand(&line, ident2)?))
         } else if let Some((ident1, ident2)) = s.split_once("OR") {
-            Ok(Self::Or(ident1.trim().to_owned(), ident2.trim().to_owned()))
+            Ok(Self::Or(operand(&line, ident1)?, operand(&line, ident2)?))
         } else if let Some((ident, shift_arg)) = s.split_once("RSHIFT") {
             Ok(Self::RightShift(
-                ident.trim().to_owned(),
-                shift_arg.trim().parse()?,
+                operand(&line, ident)?,
+                shift_amount(&line, shift_arg)?,
             ))
         } else if let Some((ident, shift_arg)) = s.split_once("LSHIFT") {
             Ok(Self::LeftShift(
-                ident.trim().to_owned(),
-                shift_arg.trim().parse()?,
+                operand(&line, ident)?,
+                shift_amount(&line, shift_arg)?,
             ))
         } else {
-            Ok(Self::Constant(s.trim().to_owned()))
+            Ok(Self::Constant(operand(&line, s)?))
         }
     }
 }
@@ -110,14 +145,30 @@ impl fmt::Display for LogicGate {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-struct Wire {
+pub struct Wire {
     logic_gate: LogicGate,
     cached_result: RefCell<Option<u16>>,
 }
 
 impl Wire {
+    /// A wire whose signal has not been computed yet
+    pub fn new(logic_gate: LogicGate) -> Self {
+        Self {
+            logic_gate,
+            cached_result: RefCell::new(None),
+        }
+    }
+
+    /// The gate driving this wire
+    pub fn logic_gate(&self) -> &LogicGate {
+        &self.logic_gate
+    }
+
+    /// Signal on the wire, computed once then cached.
+    ///
+    /// `wires` must come from `parse`: unknown wires panic and circuits that loop overflow the stack.
     #[inline]
-    fn get_wire_value(&self, wires: &FnvHashMap<String, Wire>) -> u16 {
+    pub fn get_wire_value(&self, wires: &FnvHashMap<String, Wire>) -> u16 {
         let borrow = self.cached_result.borrow();
         if let Some(cached) = *borrow {
             cached
@@ -131,44 +182,123 @@ impl Wire {
 }
 
 impl FromStr for Wire {
-    type Err = Box<dyn Error>;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Self {
-            logic_gate: s.parse()?,
-            cached_result: RefCell::new(None),
-        })
+        Ok(Self::new(s.parse()?))
     }
 }
 
 #[aoc_generator(day7)]
-fn parse(input: &str) -> Result<FnvHashMap<String, Wire>, Box<dyn Error>> {
-    let elements: Vec<_> = input
-        .lines()
+pub fn parse(input: &str) -> Result<FnvHashMap<String, Wire>, ParseError> {
+    let elements: Vec<_> = lines(input)
+        .filter(|line| !line.is_blank())
         .map(|line| {
-            let (wire, dest) = line
-                .split_once("->")
-                .ok_or_else(|| format!(r#"Could not split {:?} on "->""#, line))?;
-            Ok::<_, Box<dyn Error>>((dest.trim().to_owned(), wire.trim().parse()?))
+            let (wire, dest) = line.split_once(line.text, "->")?;
+            let wire: Wire = Line::new(wire, line.number).parse()?;
+            Ok::<_, ParseError>((line, operand(&line, dest)?, wire))
         })
         .try_collect()?;
 
-    Ok(FnvHashMap::from_iter(elements))
+    // every operand must be a signal or a wire driven by some other line
+    for (line, _, wire) in &elements {
+        for ident in wire.logic_gate.operands() {
+            if ident.parse::<u16>().is_err() && !elements.iter().any(|(_, dest, _)| dest == ident) {
+                return Err(line.invalid(
+                    operand_in(line, ident),
+                    format!("wire {:?} is never given a signal", ident),
+                ));
+            }
+        }
+    }
+
+    check_acyclic(&elements)?;
+
+    Ok(elements
+        .into_iter()
+        .map(|(_, dest, wire)| (dest, wire))
+        .collect())
+}
+
+/// Where `ident` is written in `line`, the whole line if it cannot be found
+fn operand_in<'a>(line: &Line<'a>, ident: &str) -> &'a str {
+    line.text
+        .split_whitespace()
+        .find(|word| *word == ident)
+        .unwrap_or(line.text)
+}
+
+/// Depth first search over the wires each line reads, without recursion so that long chains
+/// cannot overflow the stack: a wire that depends on itself would make `get_wire_value` do so
+fn check_acyclic(elements: &[(Line, String, Wire)]) -> Result<(), ParseError> {
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    enum Visit {
+        New,
+        InProgress,
+        Done,
+    }
+
+    // the last line driving a wire wins, like in the map built by `parse`
+    let drivers: FnvHashMap<&str, usize> = elements
+        .iter()
+        .enumerate()
+        .map(|(i, (_, dest, _))| (dest.as_str(), i))
+        .collect();
+    let mut visits = vec![Visit::New; elements.len()];
+
+    for root in 0..elements.len() {
+        if visits[root] != Visit::New {
+            continue;
+        }
+
+        visits[root] = Visit::InProgress;
+        let mut stack = vec![(root, 0)];
+        while let Some((current, next_operand)) = stack.pop() {
+            let (line, _, wire) = &elements[current];
+            let Some(ident) = wire.logic_gate.operands().get(next_operand).copied() else {
+                visits[current] = Visit::Done;
+                continue;
+            };
+
+            stack.push((current, next_operand + 1));
+            let Some(&driver) = drivers.get(ident) else {
+                continue;
+            };
+
+            match visits[driver] {
+                Visit::New => {
+                    visits[driver] = Visit::InProgress;
+                    stack.push((driver, 0));
+                }
+                Visit::InProgress => {
+                    return Err(line.invalid(
+                        operand_in(line, ident),
+                        format!("wire {:?} depends on itself", ident),
+                    ));
+                }
+                Visit::Done => (),
+            }
+        }
+    }
+
+    Ok(())
 }
 
 #[aoc(day7, part1)]
-fn part1(wires: &FnvHashMap<String, Wire>) -> Result<u16, Box<dyn Error>> {
+pub fn part1(wires: &FnvHashMap<String, Wire>) -> Result<u16, Box<dyn Error>> {
     let wires = wires.clone();
     Ok(get_wire_value(&wires, "a")?)
 }
 
 #[aoc(day7, part2)]
-fn part2(wires: &FnvHashMap<String, Wire>) -> Result<u16, Box<dyn Error>> {
+pub fn part2(wires: &FnvHashMap<String, Wire>) -> Result<u16, Box<dyn Error>> {
     let new_wires = wires.clone();
     let result = part1(wires)?;
 
     {
-        let wire_b = new_wires.get("b").unwrap();
+        let wire_b = new_wires
+            .get("b")
+            .ok_or("Key \"b\" does not exist in the hashmap")?;
         let mut borrow = wire_b.cached_result.borrow_mut();
         *borrow = Some(result);
     }
@@ -183,9 +313,15 @@ fn get_wire_value(wires: &FnvHashMap<String, Wire>, wire: &str) -> Result<u16, S
     Ok(wire.get_wire_value(wires))
 }
 
+pub const SOLUTIONS: &[&dyn Solution] = &[
+    &Solver::new(7, 1, parse, part1),
+    &Solver::new(7, 2, parse, part2),
+];
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::error::Position;
 
     const EXAMPLE_1: &str = r#"123 -> x
 456 -> y
@@ -214,4 +350,36 @@ NOT y -> i"#;
             assert_eq!(get_wire_value(&wires, wire).unwrap(), result);
         }
     }
+
+    #[test]
+    fn parse_errors() {
+        let err = parse("123 -> x\nx LSHIFT 16 -> y").unwrap_err();
+        assert_eq!(
+            err.position(),
+            Position {
+                line: 2,
+                column: 10
+            }
+        );
+
+        let err = parse("123 -> x\nx AND z -> y").unwrap_err();
+        assert_eq!(err.position(), Position { line: 2, column: 7 });
+
+        let err = parse("123 -> x\nx OR -> y").unwrap_err();
+        assert!(matches!(err, ParseError::Expected { .. }));
+
+        let err = parse("a -> b\nb -> a").unwrap_err();
+        assert_eq!(err.position(), Position { line: 2, column: 1 });
+
+        let err = parse("1 -> y\ny AND x -> z\nx -> x").unwrap_err();
+        assert_eq!(err.position(), Position { line: 3, column: 1 });
+        assert!(err.to_string().contains("depends on itself"));
+    }
+
+    #[test]
+    fn part2_without_b() {
+        let wires = parse("123 -> a").unwrap();
+        assert_eq!(part1(&wires).unwrap(), 123);
+        assert!(part2(&wires).is_err());
+    }
 }
@@ -1,33 +1,50 @@
-#[inline]
-fn count_for_string_minus_in_memory(s: &[u8]) -> usize {
-    let mut count = 2;
+use crate::{
+    error::{lines, Line, ParseError},
+    solution::{Solution, Solver},
+};
 
-    if s[0] != b'"' {
-        panic!(r#"{:?} does not start with '"' ({})"#, s, b'"');
-    }
+/// Checks `line` is a double quoted string literal whose escapes are `\\`, `\"` or `\x` and 2 hex digits
+fn check_literal(line: &Line) -> Result<(), ParseError> {
+    let text = line.text;
+    let body = line.strip_prefix(text, "\"")?;
+    let body = line.strip_suffix(body, "\"")?;
 
-    if s[s.len() - 1] != b'"' {
-        panic!(r#"{:?} does not end with '"' ({})"#, s, b'"');
+    let mut chars = body.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '"' => return Err(line.unexpected(&body[i..], c)),
+            '\\' => match chars.next() {
+                Some((_, '\\' | '"')) => (),
+                Some((_, 'x')) => {
+                    for _ in 0..2 {
+                        match chars.next() {
+                            Some((_, digit)) if digit.is_ascii_hexdigit() => (),
+                            Some((j, digit)) => return Err(line.unexpected(&body[j..], digit)),
+                            None => return Err(line.expected(&body[body.len()..], "2 hex digits")),
+                        }
+                    }
+                }
+                Some((j, other)) => return Err(line.unexpected(&body[j..], other)),
+                None => return Err(line.expected(&body[body.len()..], "an escape sequence")),
+            },
+            _ => (),
+        }
     }
 
+    Ok(())
+}
+
+/// `s` must have gone through `check_literal`
+#[inline]
+fn count_for_string_minus_in_memory(s: &[u8]) -> usize {
+    let mut count = 2;
+
     let mut i = 1;
     while i < s.len() - 1 {
-        if s[i] == b'"' {
-            panic!(r#"Unescaped '"'"#);
-        }
-
         if s[i] == b'\\' {
             i += 1;
-            if i >= s.len() - 1 {
-                panic!("Unfinished escape sequence");
-            }
-
             if s[i] == b'x' {
                 i += 2;
-                if i >= s.len() - 1 {
-                    panic!("Unfinished escape sequence");
-                }
-
                 count += 3;
             } else {
                 count += 1;
@@ -46,22 +63,38 @@ fn extra_characters_to_encode(s: &[u8]) -> usize {
     s.iter().filter(|&&c| matches!(c, b'"' | b'\\')).count() + 2
 }
 
+#[aoc_generator(day8)]
+pub fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+    lines(input)
+        .filter(|line| !line.is_blank())
+        .map(|line| {
+            check_literal(&line)?;
+            Ok(line.text.to_owned())
+        })
+        .collect()
+}
+
 #[aoc(day8, part1)]
-fn part1(input: &str) -> usize {
-    input
-        .lines()
-        .map(|line| count_for_string_minus_in_memory(line.as_bytes()))
+pub fn part1(literals: &[String]) -> usize {
+    literals
+        .iter()
+        .map(|literal| count_for_string_minus_in_memory(literal.as_bytes()))
         .sum()
 }
 
 #[aoc(day8, part2)]
-fn part2(input: &str) -> usize {
-    input
-        .lines()
-        .map(|line| extra_characters_to_encode(line.as_bytes()))
+pub fn part2(literals: &[String]) -> usize {
+    literals
+        .iter()
+        .map(|literal| extra_characters_to_encode(literal.as_bytes()))
         .sum()
 }
 
+pub const SOLUTIONS: &[&dyn Solution] = &[
+    &Solver::new(8, 1, parse, |literals| part1(literals)),
+    &Solver::new(8, 2, parse, |literals| part2(literals)),
+];
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -74,11 +107,21 @@ mod tests {
 
     #[test]
     fn part1_example1() {
-        assert_eq!(part1(EXAMPLE1), 12);
+        assert_eq!(part1(&parse(EXAMPLE1).unwrap()), 12);
     }
 
     #[test]
     fn part2_example1() {
-        assert_eq!(part2(EXAMPLE1), 19);
+        assert_eq!(part2(&parse(EXAMPLE1).unwrap()), 19);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let column = |input| parse(input).unwrap_err().position().column;
+        assert_eq!(column(r#""abc"#), 4);
+        assert_eq!(column(r#""a"b""#), 3);
+        assert_eq!(column(r#""\q""#), 3);
+        assert_eq!(column(r#""\x2g""#), 5);
+        assert_eq!(column(r#""\x2""#), 5);
     }
 }
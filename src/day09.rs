@@ -1,173 +1,50 @@
-use std::{error::Error, io};
+use std::error::Error;
 
-use fnv::{FnvHashMap, FnvHashSet};
+use crate::{
+    error::{lines, ParseError},
+    graph::{Graph, Objective},
+    solution::{Solution, Solver},
+};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-struct Edge {
-    weight: u64,
-    target_vertex: usize,
-}
-
-#[derive(Debug, Clone, PartialEq, Eq)]
-struct Graph {
-    vertices: Vec<(String, FnvHashSet<Edge>)>,
-}
-
-impl Graph {
-    #[allow(dead_code)]
-    fn write_as_gv<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
-        writeln!(writer, "graph {{\n    layout = \"circo\"\n")?;
-        for (vertex_id, (vertex, _)) in self.vertices.iter().enumerate() {
-            writeln!(writer, "    v{} [label={:?}]", vertex_id, vertex)?;
-        }
-
-        writeln!(writer)?;
-        for (vertex_id, (_, adjacents)) in self.vertices.iter().enumerate() {
-            for edge in adjacents.iter() {
-                if vertex_id < edge.target_vertex {
-                    writeln!(
-                        writer,
-                        r#"    v{} -- v{} [label="{}"]"#,
-                        vertex_id, edge.target_vertex, edge.weight
-                    )?;
-                }
-            }
-        }
-
-        writeln!(writer, "}}")?;
-
-        Ok(())
-    }
-
-    /// Assumes `self` is a complete graph
-    fn find_shortest_hamiltonian_path(&self) -> u64 {
-        (0..self.vertices.len())
-            .map(|i| self.shortest_hamiltonian_impl(i, FnvHashSet::default()))
-            .min()
-            .unwrap()
-    }
-
-    fn shortest_hamiltonian_impl(&self, current: usize, mut visited: FnvHashSet<usize>) -> u64 {
-        visited.insert(current);
-        self.vertices[current]
-            .1
-            .iter()
-            .filter_map(|edge| {
-                if visited.contains(&edge.target_vertex) {
-                    None
-                } else {
-                    Some(
-                        edge.weight
-                            + self.shortest_hamiltonian_impl(edge.target_vertex, visited.clone()),
-                    )
-                }
-            })
-            .min()
-            .unwrap_or(0)
-    }
-
-    /// Assumes `self` is a complete graph
-    fn find_longest_hamiltonian_path(&self) -> u64 {
-        (0..self.vertices.len())
-            .map(|i| self.longest_hamiltonian_impl(i, FnvHashSet::default()))
-            .max()
-            .unwrap()
-    }
-
-    fn longest_hamiltonian_impl(&self, current: usize, mut visited: FnvHashSet<usize>) -> u64 {
-        visited.insert(current);
-        self.vertices[current]
-            .1
-            .iter()
-            .filter_map(|edge| {
-                if visited.contains(&edge.target_vertex) {
-                    None
-                } else {
-                    Some(
-                        edge.weight
-                            + self.longest_hamiltonian_impl(edge.target_vertex, visited.clone()),
-                    )
-                }
-            })
-            .max()
-            .unwrap_or(0)
-    }
-}
-
-impl<'s> FromIterator<&'s str> for Graph {
-    fn from_iter<T: IntoIterator<Item = &'s str>>(iter: T) -> Self {
-        let mut vertex_name_to_id = FnvHashMap::default();
-        let mut vertices = vec![];
-        for line in iter {
-            let (destinations, distance) = line
-                .split_once('=')
-                .ok_or_else(|| format!("{:?} did not contain an '=' sign", line))
-                .unwrap();
-
-            let (dest1, dest2) = destinations
-                .split_once(" to ")
-                .ok_or_else(|| {
-                    format!(r#"{:?} did not contain the substring " to ""#, destinations)
-                })
-                .unwrap();
-
-            let dest1 = dest1.trim();
-            let dest2 = dest2.trim();
-
-            let dest1_id = if let Some(&id) = vertex_name_to_id.get(dest1) {
-                id
-            } else {
-                let new_id = vertices.len();
-                vertices.push((dest1.to_owned(), FnvHashSet::default()));
-                vertex_name_to_id.insert(dest1.to_owned(), new_id);
-
-                new_id
-            };
-
-            let dest2_id = if let Some(&id) = vertex_name_to_id.get(dest2) {
-                id
-            } else {
-                let new_id = vertices.len();
-                vertices.push((dest2.to_owned(), FnvHashSet::default()));
-                vertex_name_to_id.insert(dest2.to_owned(), new_id);
-
-                new_id
-            };
-
-            let distance = distance.trim().parse().unwrap();
-            vertices[dest1_id].1.insert(Edge {
-                weight: distance,
-                target_vertex: dest2_id,
-            });
-            vertices[dest2_id].1.insert(Edge {
-                weight: distance,
-                target_vertex: dest1_id,
-            });
-        }
-
-        Self { vertices }
+#[aoc_generator(day9)]
+pub fn parse(input: &str) -> Result<Graph, ParseError> {
+    let mut graph = Graph::default();
+    for line in lines(input).filter(|line| !line.is_blank()) {
+        let (destinations, distance) = line.split_once(line.text, "=")?;
+        let (dest1, dest2) = line.split_once(destinations, " to ")?;
+        graph.set_edge(dest1.trim(), dest2.trim(), line.number(distance)?);
     }
-}
 
-#[aoc_generator(day9)]
-fn parse(input: &str) -> Graph {
-    input.lines().collect()
+    Ok(graph)
 }
 
 #[aoc(day9, part1)]
-fn part1(graph: &Graph) -> Result<u64, Box<dyn Error>> {
+pub fn part1(graph: &Graph) -> Result<u64, Box<dyn Error>> {
     // graph.write_as_gv(&mut io::stdout())?;
-    Ok(graph.find_shortest_hamiltonian_path())
+    Ok(graph
+        .best_hamiltonian_path(Objective::Minimize)
+        .ok_or("No hamiltonian path in the graph")?)
 }
 
 #[aoc(day9, part2)]
-fn part2(graph: &Graph) -> u64 {
-    graph.find_longest_hamiltonian_path()
+pub fn part2(graph: &Graph) -> Result<u64, Box<dyn Error>> {
+    Ok(graph
+        .best_hamiltonian_path(Objective::Maximize)
+        .ok_or("No hamiltonian path in the graph")?)
 }
 
+pub const SOLUTIONS: &[&dyn Solution] = &[
+    &Solver::new(9, 1, parse, part1),
+    &Solver::new(9, 2, parse, part2),
+];
+
 #[cfg(test)]
 mod tests {
+    use itertools::Itertools;
+    use proptest::prelude::*;
+
     use super::*;
+    use crate::generators;
 
     const EXAMPLE1: &str = r#"London to Dublin = 464
 London to Belfast = 518
@@ -175,11 +52,47 @@ Dublin to Belfast = 141"#;
 
     #[test]
     fn part1_example1() {
-        assert_eq!(part1(&parse(EXAMPLE1)).unwrap(), 605);
+        assert_eq!(part1(&parse(EXAMPLE1).unwrap()).unwrap(), 605);
+    }
+
+    #[test]
+    fn repeated_distance() {
+        let graph = parse("A to B = 1\nB to A = 1\nA to B = 1").unwrap();
+        assert_eq!(graph.weight(0, 1), Some(1));
+        assert_eq!(part1(&graph).unwrap(), 1);
     }
 
     #[test]
     fn part2_example1() {
-        assert_eq!(part2(&parse(EXAMPLE1)), 982);
+        assert_eq!(part2(&parse(EXAMPLE1).unwrap()).unwrap(), 982);
+    }
+
+    #[test]
+    fn no_path() {
+        let graph = parse("").unwrap();
+        assert!(part1(&graph).is_err());
+        assert!(part2(&graph).is_err());
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(32))]
+
+        /// Checks the search against every ordering of the cities
+        #[test]
+        fn matches_every_permutation(seed in any::<u64>(), cities in 2..7usize) {
+            let graph = parse(&generators::day09(&mut generators::seeded(seed), cities)).unwrap();
+            let lengths = (0..graph.len())
+                .permutations(graph.len())
+                .map(|route| {
+                    route
+                        .windows(2)
+                        .map(|leg| graph.weight(leg[0], leg[1]).unwrap())
+                        .sum::<u64>()
+                })
+                .collect_vec();
+
+            prop_assert_eq!(part1(&graph).unwrap(), *lengths.iter().min().unwrap());
+            prop_assert_eq!(part2(&graph).unwrap(), *lengths.iter().max().unwrap());
+        }
     }
 }
@@ -1,4 +1,7 @@
-use itertools::Itertools;
+use crate::{
+    error::{Line, ParseError},
+    solution::{Solution, Solver},
+};
 
 #[inline]
 fn expand_look_and_say(source: Vec<u8>) -> Vec<u8> {
@@ -26,13 +29,26 @@ fn expand_look_and_say(source: Vec<u8>) -> Vec<u8> {
     result
 }
 
+#[aoc_generator(day10)]
+pub fn parse(input: &str) -> Result<Vec<u8>, ParseError> {
+    let line = Line::new(input.trim(), 1);
+    if line.is_blank() {
+        return Err(line.expected(line.text, "a sequence of digits"));
+    }
+
+    line.text
+        .char_indices()
+        .map(|(i, c)| {
+            c.to_digit(10)
+                .map(|digit| digit as u8)
+                .ok_or_else(|| line.unexpected(&line.text[i..], c))
+        })
+        .collect()
+}
+
 #[aoc(day10, part1)]
-fn part1(input: &str) -> usize {
-    let mut input = input
-        .trim()
-        .chars()
-        .map(|c| c.to_digit(10).unwrap() as u8)
-        .collect_vec();
+pub fn part1(digits: &[u8]) -> usize {
+    let mut input = digits.to_vec();
     for _ in 0..40 {
         input = expand_look_and_say(input);
     }
@@ -41,12 +57,8 @@ fn part1(input: &str) -> usize {
 }
 
 #[aoc(day10, part2)]
-fn part2(input: &str) -> usize {
-    let mut input = input
-        .trim()
-        .chars()
-        .map(|c| c.to_digit(10).unwrap() as u8)
-        .collect_vec();
+pub fn part2(digits: &[u8]) -> usize {
+    let mut input = digits.to_vec();
     for _ in 0..50 {
         input = expand_look_and_say(input);
     }
@@ -54,10 +66,25 @@ fn part2(input: &str) -> usize {
     input.len()
 }
 
+pub const SOLUTIONS: &[&dyn Solution] = &[
+    &Solver::new(10, 1, parse, |digits| part1(digits)),
+    &Solver::new(10, 2, parse, |digits| part2(digits)),
+];
+
 #[cfg(test)]
 mod tests {
+    use proptest::prelude::*;
+
     use super::*;
 
+    fn longest_run(digits: &[u8]) -> usize {
+        digits
+            .chunk_by(|a, b| a == b)
+            .map(<[u8]>::len)
+            .max()
+            .unwrap_or(0)
+    }
+
     #[test]
     fn part1_expand_tests() {
         assert_eq!(expand_look_and_say(vec![1]), vec![1, 1]);
@@ -72,4 +99,37 @@ mod tests {
             vec![3, 1, 2, 2, 1, 1]
         );
     }
+
+    #[test]
+    fn parse_digits() {
+        assert_eq!(
+            parse("1113122113\n").unwrap(),
+            [1, 1, 1, 3, 1, 2, 2, 1, 1, 3]
+        );
+        assert_eq!(
+            parse("11a3").unwrap_err(),
+            ParseError::UnexpectedCharacter {
+                position: crate::error::Position { line: 1, column: 3 },
+                found: 'a'
+            }
+        );
+    }
+
+    proptest! {
+        /// Holds for every real input, which never starts with a run longer than 3
+        #[test]
+        fn runs_never_exceed_three(
+            digits in prop::collection::vec(1u8..=3, 1..20)
+                .prop_filter("a run longer than 3", |digits| longest_run(digits) <= 3)
+        ) {
+            let mut digits = digits;
+            for _ in 0..12 {
+                let runs = digits.chunk_by(|a, b| a == b).count();
+                digits = expand_look_and_say(digits);
+                prop_assert_eq!(digits.len(), 2 * runs);
+                prop_assert!(longest_run(&digits) <= 3);
+                prop_assert!(digits.iter().all(|&digit| (1..=3).contains(&digit)));
+            }
+        }
+    }
 }
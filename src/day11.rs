@@ -1,8 +1,11 @@
-use std::error::Error;
+use crate::{
+    error::{Line, ParseError},
+    solution::{Solution, Solver},
+};
 
 use itertools::Itertools;
 
-type Password = [char; 8];
+pub type Password = [char; 8];
 
 #[derive(Debug, Clone)]
 struct PassIter {
@@ -77,19 +80,30 @@ fn is_in_sequence(a: char, b: char, c: char) -> bool {
 }
 
 #[aoc_generator(day11)]
-fn parse(input: &str) -> Result<Password, Box<dyn Error>> {
-    let result = Password::try_from(input.trim().chars().collect_vec());
+pub fn parse(input: &str) -> Result<Password, ParseError> {
+    let line = Line::new(input.trim(), 1);
+    if let Some((i, c)) = line
+        .text
+        .char_indices()
+        .find(|(_, c)| !c.is_ascii_lowercase())
+    {
+        return Err(line.unexpected(&line.text[i..], c));
+    }
+
+    let result = Password::try_from(line.text.chars().collect_vec());
     result.map_err(|vec| {
-        format!(
-            "There was not 8 characters in the string (got {} chars)",
-            vec.len()
+        line.invalid(
+            line.text,
+            format!(
+                "There was not 8 characters in the string (got {} chars)",
+                vec.len()
+            ),
         )
-        .into()
     })
 }
 
 #[aoc(day11, part1)]
-fn part1(initial_pass: &Password) -> String {
+pub fn part1(initial_pass: &Password) -> String {
     for pass in PassIter::from(*initial_pass) {
         // println!("{:?}", pass);
         if p1_is_valid_pass(&pass) {
@@ -101,7 +115,7 @@ fn part1(initial_pass: &Password) -> String {
 }
 
 #[aoc(day11, part2)]
-fn part2(initial_pass: &Password) -> String {
+pub fn part2(initial_pass: &Password) -> String {
     let mut first_matched = false;
     for pass in PassIter::from(*initial_pass) {
         // println!("{:?}", pass);
@@ -117,6 +131,11 @@ fn part2(initial_pass: &Password) -> String {
     unreachable!("No password matched")
 }
 
+pub const SOLUTIONS: &[&dyn Solution] = &[
+    &Solver::new(11, 1, parse, part1),
+    &Solver::new(11, 2, parse, part2),
+];
+
 #[cfg(test)]
 mod tests {
     use super::*;
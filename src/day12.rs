@@ -1,5 +1,10 @@
 use serde_json::Value;
 
+use crate::{
+    error::{ParseError, Position},
+    solution::{Solution, Solver},
+};
+
 #[inline]
 fn sum_all_nums(value: &Value) -> i64 {
     match value {
@@ -35,20 +40,36 @@ fn sum_ignore_red(value: &Value) -> i64 {
 }
 
 #[aoc_generator(day12)]
-fn parse(input: &str) -> serde_json::Result<Value> {
-    serde_json::from_str(input)
+pub fn parse(input: &str) -> Result<Value, ParseError> {
+    serde_json::from_str(input).map_err(|err| {
+        // serde_json already appends the position to its messages
+        let message = err.to_string();
+        let suffix = format!(" at line {} column {}", err.line(), err.column());
+        ParseError::Invalid {
+            position: Position {
+                line: err.line(),
+                column: err.column(),
+            },
+            message: message.strip_suffix(&suffix).unwrap_or(&message).to_owned(),
+        }
+    })
 }
 
 #[aoc(day12, part1)]
-fn part1(value: &Value) -> i64 {
+pub fn part1(value: &Value) -> i64 {
     sum_all_nums(value)
 }
 
 #[aoc(day12, part2)]
-fn part2(value: &Value) -> i64 {
+pub fn part2(value: &Value) -> i64 {
     sum_ignore_red(value)
 }
 
+pub const SOLUTIONS: &[&dyn Solution] = &[
+    &Solver::new(12, 1, parse, part1),
+    &Solver::new(12, 2, parse, part2),
+];
+
 #[cfg(test)]
 mod tests {
     use serde_json::json;
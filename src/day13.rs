@@ -0,0 +1,98 @@
+use std::error::Error;
+
+use crate::{
+    error::{lines, Line, ParseError},
+    graph::{Graph, Objective},
+    solution::{Solution, Solver},
+};
+
+const YOURSELF: &str = "yourself";
+
+/// Parses `Alice would gain 54 happiness units by sitting next to Bob.`
+#[inline]
+fn parse_line<'a>(line: &Line<'a>) -> Result<(&'a str, &'a str, i64), ParseError> {
+    let text = line.text.trim().trim_end_matches('.');
+    let (person, rest) = line.split_once(text, " would ")?;
+    let (change, neighbour) = line.split_once(rest, " happiness units by sitting next to ")?;
+
+    let change = if let Some(amount) = change.strip_prefix("gain ") {
+        line.number::<i64>(amount)?
+    } else if let Some(amount) = change.strip_prefix("lose ") {
+        -line.number::<i64>(amount)?
+    } else {
+        return Err(line.expected(change, r#""gain " or "lose ""#));
+    };
+
+    Ok((person, neighbour, change))
+}
+
+/// Both directions of a pair end up on the same undirected edge,
+/// which is exactly the happiness change of seating them together
+#[aoc_generator(day13)]
+pub fn parse(input: &str) -> Result<Graph<i64>, ParseError> {
+    let mut graph = Graph::default();
+    for line in lines(input).filter(|line| !line.is_blank()) {
+        let (person, neighbour, change) = parse_line(&line)?;
+        graph.add_to_edge(person, neighbour, change);
+    }
+
+    Ok(graph)
+}
+
+#[aoc(day13, part1)]
+pub fn part1(graph: &Graph<i64>) -> Result<i64, Box<dyn Error>> {
+    Ok(graph
+        .best_hamiltonian_cycle(Objective::Maximize)
+        .ok_or("No seating arrangement found")?)
+}
+
+#[aoc(day13, part2)]
+pub fn part2(graph: &Graph<i64>) -> Result<i64, Box<dyn Error>> {
+    let mut graph = graph.clone();
+    let guests: Vec<_> = graph.vertex_names().map(str::to_owned).collect();
+    for guest in guests {
+        graph.add_to_edge(YOURSELF, &guest, 0);
+    }
+
+    part1(&graph)
+}
+
+pub const SOLUTIONS: &[&dyn Solution] = &[
+    &Solver::new(13, 1, parse, part1),
+    &Solver::new(13, 2, parse, part2),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE1: &str = r#"Alice would gain 54 happiness units by sitting next to Bob.
+Alice would lose 79 happiness units by sitting next to Carol.
+Alice would lose 2 happiness units by sitting next to David.
+Bob would gain 83 happiness units by sitting next to Alice.
+Bob would lose 7 happiness units by sitting next to Carol.
+Bob would lose 63 happiness units by sitting next to David.
+Carol would lose 62 happiness units by sitting next to Alice.
+Carol would gain 60 happiness units by sitting next to Bob.
+Carol would gain 55 happiness units by sitting next to David.
+David would gain 46 happiness units by sitting next to Alice.
+David would lose 7 happiness units by sitting next to Bob.
+David would gain 41 happiness units by sitting next to Carol."#;
+
+    #[test]
+    fn part1_example1() {
+        assert_eq!(part1(&parse(EXAMPLE1).unwrap()).unwrap(), 330);
+    }
+
+    #[test]
+    fn part2_example1() {
+        // with a neutral guest the best cycle is the best open path
+        assert_eq!(
+            part2(&parse(EXAMPLE1).unwrap()).unwrap(),
+            parse(EXAMPLE1)
+                .unwrap()
+                .best_hamiltonian_path(Objective::Maximize)
+                .unwrap()
+        );
+    }
+}
@@ -0,0 +1,211 @@
+use std::str::FromStr;
+
+use crate::{
+    error::{parse_lines, Line, ParseError},
+    solution::{Solution, Solver},
+};
+
+const RACE_DURATION: u32 = 2503;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Reindeer {
+    pub name: String,
+    pub speed: u32,
+    pub fly_time: u32,
+    pub rest_time: u32,
+}
+
+impl Reindeer {
+    /// Closed form distance travelled after `seconds`
+    #[inline]
+    pub fn distance_after(&self, seconds: u32) -> u32 {
+        let cycle = self.fly_time + self.rest_time;
+        let full_cycles = seconds / cycle;
+        let remainder = seconds % cycle;
+
+        self.speed * (full_cycles * self.fly_time + remainder.min(self.fly_time))
+    }
+
+    /// Whether the reindeer is flying during the second starting at `second` (0-indexed)
+    #[inline]
+    pub fn is_flying_at(&self, second: u32) -> bool {
+        second % (self.fly_time + self.rest_time) < self.fly_time
+    }
+}
+
+impl FromStr for Reindeer {
+    type Err = ParseError;
+
+    /// `Comet can fly 14 km/s for 10 seconds, but then must rest for 127 seconds.`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let line = Line::new(s, 1);
+        let (name, rest) = line.split_once(s, " can fly ")?;
+        let (speed, rest) = line.split_once(rest, " km/s for ")?;
+        let (fly_time, rest) = line.split_once(rest, " seconds, but then must rest for ")?;
+        let rest_time = line.strip_suffix(rest.trim(), " seconds.")?;
+
+        let reindeer = Self {
+            name: name.trim().to_owned(),
+            speed: line.number(speed)?,
+            fly_time: line.number(fly_time)?,
+            rest_time: line.number(rest_time)?,
+        };
+
+        if reindeer.fly_time + reindeer.rest_time == 0 {
+            return Err(line.invalid(
+                fly_time,
+                format!("{:?} never flies nor rests", reindeer.name),
+            ));
+        }
+
+        Ok(reindeer)
+    }
+}
+
+/// Tick by tick simulation of the race, awarding a point to every leader each second
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Race<'r> {
+    reindeers: &'r [Reindeer],
+    elapsed: u32,
+    distances: Vec<u32>,
+    points: Vec<u32>,
+}
+
+impl<'r> Race<'r> {
+    /// A race that has not started yet, every reindeer at 0 km and 0 points
+    pub fn new(reindeers: &'r [Reindeer]) -> Self {
+        Self {
+            reindeers,
+            elapsed: 0,
+            distances: vec![0; reindeers.len()],
+            points: vec![0; reindeers.len()],
+        }
+    }
+
+    fn tick(&mut self) {
+        for (reindeer, distance) in self.reindeers.iter().zip(self.distances.iter_mut()) {
+            if reindeer.is_flying_at(self.elapsed) {
+                *distance += reindeer.speed;
+            }
+        }
+
+        self.elapsed += 1;
+
+        let Some(&lead) = self.distances.iter().max() else {
+            return;
+        };
+
+        for (distance, points) in self.distances.iter().zip(self.points.iter_mut()) {
+            if *distance == lead {
+                *points += 1;
+            }
+        }
+    }
+
+    /// Advances the race until `seconds` have elapsed (does nothing if already past it)
+    pub fn run_until(&mut self, seconds: u32) {
+        while self.elapsed < seconds {
+            self.tick();
+        }
+    }
+
+    /// `(name, distance, points)` sorted by points then distance, best first
+    pub fn leaderboard(&self) -> Vec<(&'r str, u32, u32)> {
+        let mut board: Vec<_> = self
+            .reindeers
+            .iter()
+            .zip(self.distances.iter().zip(self.points.iter()))
+            .map(|(reindeer, (&distance, &points))| (reindeer.name.as_str(), distance, points))
+            .collect();
+
+        board.sort_by(|a, b| b.2.cmp(&a.2).then(b.1.cmp(&a.1)));
+        board
+    }
+}
+
+#[aoc_generator(day14)]
+pub fn parse(input: &str) -> Result<Vec<Reindeer>, ParseError> {
+    parse_lines(input)
+}
+
+fn furthest_after(reindeers: &[Reindeer], seconds: u32) -> u32 {
+    reindeers
+        .iter()
+        .map(|reindeer| reindeer.distance_after(seconds))
+        .max()
+        .unwrap_or(0)
+}
+
+fn most_points_after(reindeers: &[Reindeer], seconds: u32) -> u32 {
+    let mut race = Race::new(reindeers);
+    race.run_until(seconds);
+    race.leaderboard()
+        .first()
+        .map_or(0, |&(_, _, points)| points)
+}
+
+#[aoc(day14, part1)]
+pub fn part1(reindeers: &[Reindeer]) -> u32 {
+    furthest_after(reindeers, RACE_DURATION)
+}
+
+#[aoc(day14, part2)]
+pub fn part2(reindeers: &[Reindeer]) -> u32 {
+    most_points_after(reindeers, RACE_DURATION)
+}
+
+pub const SOLUTIONS: &[&dyn Solution] = &[
+    &Solver::new(14, 1, parse, |reindeers| part1(reindeers)),
+    &Solver::new(14, 2, parse, |reindeers| part2(reindeers)),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE1: &str = r#"Comet can fly 14 km/s for 10 seconds, but then must rest for 127 seconds.
+Dancer can fly 16 km/s for 11 seconds, but then must rest for 162 seconds."#;
+
+    #[test]
+    fn part1_example1() {
+        assert_eq!(furthest_after(&parse(EXAMPLE1).unwrap(), 1000), 1120);
+    }
+
+    #[test]
+    fn part2_example1() {
+        assert_eq!(most_points_after(&parse(EXAMPLE1).unwrap(), 1000), 689);
+    }
+
+    #[test]
+    fn simulation_matches_closed_form() {
+        let reindeers = parse(EXAMPLE1).unwrap();
+        let mut race = Race::new(&reindeers);
+        for second in [1, 10, 11, 12, 138, 174, 1000] {
+            race.run_until(second);
+            for (reindeer, &distance) in reindeers.iter().zip(race.distances.iter()) {
+                assert_eq!(reindeer.distance_after(second), distance);
+            }
+        }
+    }
+
+    #[test]
+    fn leaderboard_example1() {
+        let reindeers = parse(EXAMPLE1).unwrap();
+        let mut race = Race::new(&reindeers);
+
+        race.run_until(1);
+        assert_eq!(race.leaderboard(), [("Dancer", 16, 1), ("Comet", 14, 0)]);
+
+        race.run_until(140);
+        assert_eq!(
+            race.leaderboard(),
+            [("Dancer", 176, 139), ("Comet", 182, 1)]
+        );
+
+        race.run_until(1000);
+        assert_eq!(
+            race.leaderboard(),
+            [("Dancer", 1056, 689), ("Comet", 1120, 312)]
+        );
+    }
+}
@@ -0,0 +1,215 @@
+use std::{error::Error, str::FromStr};
+
+use crate::{
+    error::{parse_lines, Line, ParseError},
+    solution::{Solution, Solver},
+};
+
+const TEASPOONS: u32 = 100;
+const CALORIE_TARGET: i64 = 500;
+const SCORED_PROPERTIES: [&str; 4] = ["capacity", "durability", "flavor", "texture"];
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Ingredient {
+    pub name: String,
+    /// In the same order as `SCORED_PROPERTIES`
+    pub properties: [i64; 4],
+    pub calories: i64,
+}
+
+impl FromStr for Ingredient {
+    type Err = ParseError;
+
+    /// `Butterscotch: capacity -1, durability -2, flavor 6, texture 3, calories 8`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let line = Line::new(s, 1);
+        let (name, rest) = line.split_once(s, ":")?;
+
+        let mut properties = [None; 4];
+        let mut calories = None;
+        for property in rest.split(',') {
+            let (property, value) = line.split_once(property.trim(), " ")?;
+            let value: i64 = line.number(value)?;
+
+            if property == "calories" {
+                calories = Some(value);
+            } else if let Some(index) = SCORED_PROPERTIES.iter().position(|&p| p == property) {
+                properties[index] = Some(value);
+            } else {
+                return Err(line.invalid(property, format!("Unknown property {:?}", property)));
+            }
+        }
+
+        let end = &s[s.len()..];
+        let mut scored = [0; 4];
+        for (i, property) in properties.into_iter().enumerate() {
+            scored[i] = property.ok_or_else(|| {
+                line.expected(end, format!("property {:?}", SCORED_PROPERTIES[i]))
+            })?;
+        }
+
+        Ok(Self {
+            name: name.trim().to_owned(),
+            properties: scored,
+            calories: calories.ok_or_else(|| line.expected(end, r#"property "calories""#))?,
+        })
+    }
+}
+
+/// Iterates over every way to split `total` into `parts` ordered non-negative integers,
+/// e.g. `[0, 2]`, `[1, 1]`, `[2, 0]` for 2 into 2 parts
+#[derive(Debug, Clone)]
+pub struct Partitions {
+    total: u32,
+    current: Vec<u32>,
+    done: bool,
+}
+
+impl Partitions {
+    /// Starts at `[0, ..., 0, total]`, yields nothing when `parts` is 0
+    pub fn new(total: u32, parts: usize) -> Self {
+        let mut current = vec![0; parts];
+        if let Some(last) = current.last_mut() {
+            *last = total;
+        }
+
+        Self {
+            total,
+            current,
+            done: parts == 0,
+        }
+    }
+}
+
+impl Iterator for Partitions {
+    type Item = Vec<u32>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let result = self.current.clone();
+
+        // the first `parts - 1` values act like an odometer whose sum may not exceed `total`,
+        // the last value takes whatever is left
+        let free = self.current.len() - 1;
+        let mut used: u32 = self.current[..free].iter().sum();
+        self.done = true;
+        for i in (0..free).rev() {
+            if used < self.total {
+                self.current[i] += 1;
+                used += 1;
+                self.done = false;
+                break;
+            }
+
+            used -= self.current[i];
+            self.current[i] = 0;
+        }
+
+        self.current[free] = self.total - used;
+        Some(result)
+    }
+}
+
+/// Returns `None` if `calorie_target` is given and not met
+#[inline]
+fn score(ingredients: &[Ingredient], amounts: &[u32], calorie_target: Option<i64>) -> Option<i64> {
+    if let Some(target) = calorie_target {
+        let calories: i64 = ingredients
+            .iter()
+            .zip(amounts)
+            .map(|(ingredient, &amount)| ingredient.calories * amount as i64)
+            .sum();
+
+        if calories != target {
+            return None;
+        }
+    }
+
+    Some(
+        (0..SCORED_PROPERTIES.len())
+            .map(|property| {
+                ingredients
+                    .iter()
+                    .zip(amounts)
+                    .map(|(ingredient, &amount)| ingredient.properties[property] * amount as i64)
+                    .sum::<i64>()
+                    .max(0)
+            })
+            .product(),
+    )
+}
+
+fn best_score(ingredients: &[Ingredient], calorie_target: Option<i64>) -> Option<i64> {
+    Partitions::new(TEASPOONS, ingredients.len())
+        .filter_map(|amounts| score(ingredients, &amounts, calorie_target))
+        .max()
+}
+
+#[aoc_generator(day15)]
+pub fn parse(input: &str) -> Result<Vec<Ingredient>, ParseError> {
+    parse_lines(input)
+}
+
+#[aoc(day15, part1)]
+pub fn part1(ingredients: &[Ingredient]) -> Result<i64, Box<dyn Error>> {
+    Ok(best_score(ingredients, None).ok_or("No ingredients")?)
+}
+
+#[aoc(day15, part2)]
+pub fn part2(ingredients: &[Ingredient]) -> Result<i64, Box<dyn Error>> {
+    Ok(best_score(ingredients, Some(CALORIE_TARGET))
+        .ok_or_else(|| format!("No recipe has exactly {} calories", CALORIE_TARGET))?)
+}
+
+pub const SOLUTIONS: &[&dyn Solution] = &[
+    &Solver::new(15, 1, parse, |ingredients| part1(ingredients)),
+    &Solver::new(15, 2, parse, |ingredients| part2(ingredients)),
+];
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+
+    use super::*;
+
+    const EXAMPLE1: &str = r#"Butterscotch: capacity -1, durability -2, flavor 6, texture 3, calories 8
+Cinnamon: capacity 2, durability 3, flavor -2, texture -1, calories 3"#;
+
+    #[test]
+    fn partitions_small() {
+        assert_eq!(
+            Partitions::new(2, 3).collect_vec(),
+            vec![
+                vec![0, 0, 2],
+                vec![0, 1, 1],
+                vec![0, 2, 0],
+                vec![1, 0, 1],
+                vec![1, 1, 0],
+                vec![2, 0, 0],
+            ]
+        );
+        assert_eq!(Partitions::new(5, 1).collect_vec(), vec![vec![5]]);
+        assert_eq!(Partitions::new(5, 0).count(), 0);
+    }
+
+    #[test]
+    fn partitions_count() {
+        // C(100 + 3, 3)
+        assert_eq!(Partitions::new(100, 4).count(), 176_851);
+        assert!(Partitions::new(100, 4).all(|p| p.iter().sum::<u32>() == 100));
+    }
+
+    #[test]
+    fn part1_example1() {
+        assert_eq!(part1(&parse(EXAMPLE1).unwrap()).unwrap(), 62_842_880);
+    }
+
+    #[test]
+    fn part2_example1() {
+        assert_eq!(part2(&parse(EXAMPLE1).unwrap()).unwrap(), 57_600_000);
+    }
+}
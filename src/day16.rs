@@ -0,0 +1,189 @@
+use std::{error::Error, str::FromStr};
+
+use fnv::FnvHashMap;
+use itertools::Itertools;
+
+use crate::{
+    error::{parse_lines, Line, ParseError},
+    solution::{Solution, Solver},
+};
+
+/// What the MFCSAM detected on the gift
+pub(crate) const MFCSAM_READOUT: [(&str, u32); 10] = [
+    ("children", 3),
+    ("cats", 7),
+    ("samoyeds", 2),
+    ("pomeranians", 3),
+    ("akitas", 0),
+    ("vizslas", 0),
+    ("goldfish", 5),
+    ("trees", 3),
+    ("cars", 2),
+    ("perfumes", 1),
+];
+
+/// Properties not listed in a rule table are compared with `Comparison::Exact`
+pub const PART1_RULES: [(&str, Comparison); 0] = [];
+
+/// The retroencabulator's outdated readings of part 2
+pub const PART2_RULES: [(&str, Comparison); 4] = [
+    ("cats", Comparison::GreaterThan),
+    ("trees", Comparison::GreaterThan),
+    ("pomeranians", Comparison::FewerThan),
+    ("goldfish", Comparison::FewerThan),
+];
+
+/// How a remembered amount relates to the MFCSAM readout
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Comparison {
+    #[default]
+    Exact,
+    GreaterThan,
+    FewerThan,
+}
+
+impl Comparison {
+    #[inline]
+    pub fn matches(self, remembered: u32, readout: u32) -> bool {
+        match self {
+            Self::Exact => remembered == readout,
+            Self::GreaterThan => remembered > readout,
+            Self::FewerThan => remembered < readout,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sue {
+    pub number: u32,
+    pub properties: FnvHashMap<String, u32>,
+}
+
+impl Sue {
+    /// Properties the Sue record does not mention are unknown and therefore match anything
+    pub fn matches(&self, readout: &[(&str, u32)], rules: &[(&str, Comparison)]) -> bool {
+        readout.iter().all(|&(property, expected)| {
+            let Some(&remembered) = self.properties.get(property) else {
+                return true;
+            };
+
+            rules
+                .iter()
+                .find(|&&(name, _)| name == property)
+                .map(|&(_, comparison)| comparison)
+                .unwrap_or_default()
+                .matches(remembered, expected)
+        })
+    }
+}
+
+impl FromStr for Sue {
+    type Err = ParseError;
+
+    /// `Sue 1: goldfish: 6, trees: 9, akitas: 0`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let line = Line::new(s, 1);
+        let rest = line.strip_prefix(s, "Sue ")?;
+        let (number, rest) = line.split_once(rest, ":")?;
+
+        let properties = rest
+            .split(',')
+            .filter(|property| !property.trim().is_empty())
+            .map(|property| {
+                let (name, amount) = line.split_once(property, ":")?;
+                Ok::<_, ParseError>((name.trim().to_owned(), line.number(amount)?))
+            })
+            .try_collect()?;
+
+        Ok(Self {
+            number: line.number(number)?,
+            properties,
+        })
+    }
+}
+
+#[aoc_generator(day16)]
+pub fn parse(input: &str) -> Result<Vec<Sue>, ParseError> {
+    parse_lines(input)
+}
+
+/// The number of the only Sue agreeing with `readout` under `rules`
+pub fn find_sue(
+    sues: &[Sue],
+    readout: &[(&str, u32)],
+    rules: &[(&str, Comparison)],
+) -> Result<u32, Box<dyn Error>> {
+    let matching = sues
+        .iter()
+        .filter(|sue| sue.matches(readout, rules))
+        .map(|sue| sue.number)
+        .collect_vec();
+
+    match matching.as_slice() {
+        [number] => Ok(*number),
+        [] => Err("No Sue matched the MFCSAM readout".into()),
+        _ => Err(format!("Several Sues matched the MFCSAM readout: {:?}", matching).into()),
+    }
+}
+
+#[aoc(day16, part1)]
+pub fn part1(sues: &[Sue]) -> Result<u32, Box<dyn Error>> {
+    find_sue(sues, &MFCSAM_READOUT, &PART1_RULES)
+}
+
+#[aoc(day16, part2)]
+pub fn part2(sues: &[Sue]) -> Result<u32, Box<dyn Error>> {
+    find_sue(sues, &MFCSAM_READOUT, &PART2_RULES)
+}
+
+pub const SOLUTIONS: &[&dyn Solution] = &[
+    &Solver::new(16, 1, parse, |sues| part1(sues)),
+    &Solver::new(16, 2, parse, |sues| part2(sues)),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE1: &str = r#"Sue 1: cars: 9, akitas: 3, goldfish: 0
+Sue 2: children: 3, cats: 7, goldfish: 5
+Sue 3: cats: 8, trees: 4, goldfish: 4
+Sue 4: vizslas: 0, perfumes: 1, pomeranians: 3"#;
+
+    #[test]
+    fn parse_example1() {
+        let sues = parse(EXAMPLE1).unwrap();
+        assert_eq!(sues.len(), 4);
+        assert_eq!(sues[0].number, 1);
+        assert_eq!(sues[0].properties.get("akitas"), Some(&3));
+        assert_eq!(sues[3].properties.len(), 3);
+    }
+
+    #[test]
+    fn part1_ambiguous() {
+        // both Sue 2 and Sue 4 agree with the readout
+        assert!(part1(&parse(EXAMPLE1).unwrap()).is_err());
+    }
+
+    #[test]
+    fn part1_example1() {
+        let sues = parse(EXAMPLE1).unwrap();
+        assert_eq!(part1(&sues[..3]).unwrap(), 2);
+    }
+
+    #[test]
+    fn custom_rules() {
+        let sues = parse(EXAMPLE1).unwrap();
+        let readout = [("goldfish", 1), ("cats", 8), ("perfumes", 2)];
+        assert_eq!(
+            find_sue(&sues, &readout, &[("goldfish", Comparison::GreaterThan)]).unwrap(),
+            3
+        );
+    }
+
+    #[test]
+    fn part2_example1() {
+        let sues = parse(EXAMPLE1).unwrap();
+        assert_eq!(part2(&sues[..3]).unwrap(), 3);
+    }
+}
@@ -0,0 +1,92 @@
+use crate::{
+    error::{parse_numbers, ParseError},
+    solution::{Solution, Solver},
+};
+
+const EGGNOG_LITERS: usize = 150;
+
+/// `combinations[k][v]` is the number of ways to pick `k` containers holding exactly `v` liters
+fn combinations_by_count(containers: &[usize], target: usize) -> Vec<Vec<u64>> {
+    let mut combinations = vec![vec![0_u64; target + 1]; containers.len() + 1];
+    combinations[0][0] = 1;
+
+    for (i, &size) in containers.iter().enumerate() {
+        if size > target {
+            continue;
+        }
+
+        // go backwards so each container is used at most once
+        for count in (0..=i).rev() {
+            for volume in (0..=(target - size)).rev() {
+                let ways = combinations[count][volume];
+                if ways > 0 {
+                    combinations[count + 1][volume + size] += ways;
+                }
+            }
+        }
+    }
+
+    combinations
+}
+
+fn count_combinations(containers: &[usize], target: usize) -> u64 {
+    combinations_by_count(containers, target)
+        .iter()
+        .map(|by_volume| by_volume[target])
+        .sum()
+}
+
+/// Number of combinations that use the fewest possible containers
+fn count_minimal_combinations(containers: &[usize], target: usize) -> u64 {
+    combinations_by_count(containers, target)
+        .iter()
+        .map(|by_volume| by_volume[target])
+        .find(|&ways| ways > 0)
+        .unwrap_or(0)
+}
+
+#[aoc_generator(day17)]
+pub fn parse(input: &str) -> Result<Vec<usize>, ParseError> {
+    parse_numbers(input)
+}
+
+#[aoc(day17, part1)]
+pub fn part1(containers: &[usize]) -> u64 {
+    count_combinations(containers, EGGNOG_LITERS)
+}
+
+#[aoc(day17, part2)]
+pub fn part2(containers: &[usize]) -> u64 {
+    count_minimal_combinations(containers, EGGNOG_LITERS)
+}
+
+pub const SOLUTIONS: &[&dyn Solution] = &[
+    &Solver::new(17, 1, parse, |containers| part1(containers)),
+    &Solver::new(17, 2, parse, |containers| part2(containers)),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE1: &str = "20\n15\n10\n5\n5";
+
+    #[test]
+    fn part1_example1() {
+        assert_eq!(count_combinations(&parse(EXAMPLE1).unwrap(), 25), 4);
+    }
+
+    #[test]
+    fn part2_example1() {
+        assert_eq!(count_minimal_combinations(&parse(EXAMPLE1).unwrap(), 25), 3);
+    }
+
+    #[test]
+    fn many_containers() {
+        // 40 containers of 1 liter, C(40, 20) ways to hold 20 liters
+        let containers = vec![1; 40];
+        assert_eq!(count_combinations(&containers, 20), 137_846_528_820);
+        assert_eq!(count_minimal_combinations(&containers, 20), 137_846_528_820);
+        assert_eq!(count_combinations(&containers, 41), 0);
+    }
+}
@@ -0,0 +1,127 @@
+use crate::{
+    error::{lines, missing, ParseError},
+    grid::Grid,
+    solution::{Solution, Solver},
+};
+
+const STEPS: usize = 100;
+
+#[inline]
+fn turn_on_corners(grid: &mut Grid<bool>) {
+    let last_row = grid.rows() - 1;
+    let last_col = grid.cols() - 1;
+    for corner in [(0, 0), (0, last_col), (last_row, 0), (last_row, last_col)] {
+        grid[corner] = true;
+    }
+}
+
+/// A light stays on with 2 or 3 neighbours on, and turns on with exactly 3 neighbours on
+fn step(grid: &Grid<bool>, stuck_corners: bool) -> Grid<bool> {
+    let mut next = Grid::new(grid.rows(), grid.cols());
+    for row in 0..grid.rows() {
+        for col in 0..grid.cols() {
+            let neighbours_on = grid.neighbours(row, col).filter(|&pos| grid[pos]).count();
+
+            next[(row, col)] = matches!((grid[(row, col)], neighbours_on), (true, 2) | (_, 3));
+        }
+    }
+
+    if stuck_corners {
+        turn_on_corners(&mut next);
+    }
+
+    next
+}
+
+fn animate(grid: &Grid<bool>, steps: usize, stuck_corners: bool) -> Grid<bool> {
+    let mut grid = grid.clone();
+    if stuck_corners {
+        turn_on_corners(&mut grid);
+    }
+
+    for _ in 0..steps {
+        grid = step(&grid, stuck_corners);
+    }
+
+    grid
+}
+
+#[inline]
+fn lights_on(grid: &Grid<bool>) -> usize {
+    grid.iter().filter(|&&is_on| is_on).count()
+}
+
+#[aoc_generator(day18)]
+pub fn parse(input: &str) -> Result<Grid<bool>, ParseError> {
+    let mut rows = vec![];
+    let mut first_row_len = None;
+    for line in lines(input).filter(|line| !line.is_blank()) {
+        let text = line.text.trim();
+        let row = text
+            .char_indices()
+            .map(|(i, c)| match c {
+                '#' => Ok(true),
+                '.' => Ok(false),
+                _ => Err(line.unexpected(&text[i..], c)),
+            })
+            .collect::<Result<Box<[_]>, _>>()?;
+
+        let expected_len = *first_row_len.get_or_insert(row.len());
+        if row.len() != expected_len {
+            return Err(line.invalid(
+                text,
+                format!("row has {} lights instead of {}", row.len(), expected_len),
+            ));
+        }
+
+        rows.push(row);
+    }
+
+    match Grid::from_rows(rows) {
+        Some(grid) if grid.rows() > 0 && grid.cols() > 0 => Ok(grid),
+        _ => Err(missing(input, "a grid of lights")),
+    }
+}
+
+#[aoc(day18, part1)]
+pub fn part1(grid: &Grid<bool>) -> usize {
+    lights_on(&animate(grid, STEPS, false))
+}
+
+#[aoc(day18, part2)]
+pub fn part2(grid: &Grid<bool>) -> usize {
+    lights_on(&animate(grid, STEPS, true))
+}
+
+pub const SOLUTIONS: &[&dyn Solution] = &[
+    &Solver::new(18, 1, parse, part1),
+    &Solver::new(18, 2, parse, part2),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE1: &str = r#".#.#.#
+...##.
+#....#
+..#...
+#.#..#
+####.."#;
+
+    #[test]
+    fn part1_example1() {
+        let grid = parse(EXAMPLE1).unwrap();
+        assert_eq!(lights_on(&animate(&grid, 4, false)), 4);
+        assert_eq!(
+            animate(&grid, 4, false),
+            parse("......\n......\n..##..\n..##..\n......\n......").unwrap()
+        );
+    }
+
+    #[test]
+    fn part2_example1() {
+        let grid = parse(EXAMPLE1).unwrap();
+        assert_eq!(lights_on(&animate(&grid, 5, true)), 17);
+    }
+}
@@ -0,0 +1,327 @@
+use std::error::Error;
+
+use fnv::{FnvHashMap, FnvHashSet};
+use itertools::Itertools;
+
+use crate::{
+    error::{lines, missing, Line, ParseError},
+    solution::{Solution, Solver},
+};
+
+type Element = u8;
+type Molecule = Vec<Element>;
+
+const ELECTRON: &str = "e";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Chemistry {
+    /// Element symbols, indexed by `Element`
+    symbols: Vec<String>,
+    replacements: Vec<(Element, Molecule)>,
+    medicine: Molecule,
+}
+
+impl Chemistry {
+    #[inline]
+    fn element(&self, symbol: &str) -> Option<Element> {
+        self.symbols
+            .iter()
+            .position(|s| s == symbol)
+            .map(|id| id as Element)
+    }
+
+    #[inline]
+    fn count(&self, symbol: &str) -> usize {
+        self.element(symbol).map_or(0, |element| {
+            self.medicine.iter().filter(|&&e| e == element).count()
+        })
+    }
+}
+
+/// Splits a molecule into element symbols: an uppercase letter followed by lowercase letters,
+/// or the lone electron `e`
+fn tokenize<'a>(line: &Line<'a>, molecule: &'a str) -> Result<Vec<&'a str>, ParseError> {
+    let molecule = molecule.trim();
+    if molecule == ELECTRON {
+        return Ok(vec![ELECTRON]);
+    }
+
+    if molecule.is_empty() {
+        return Err(line.expected(molecule, "a molecule"));
+    }
+
+    let mut tokens = vec![];
+    let mut start = None;
+    for (i, c) in molecule.char_indices() {
+        if c.is_ascii_uppercase() {
+            if let Some(start) = start {
+                tokens.push(&molecule[start..i]);
+            }
+
+            start = Some(i);
+        } else if !c.is_ascii_lowercase() || start.is_none() {
+            return Err(line.unexpected(&molecule[i..], c));
+        }
+    }
+
+    if let Some(start) = start {
+        tokens.push(&molecule[start..]);
+    }
+
+    Ok(tokens)
+}
+
+#[aoc_generator(day19)]
+pub fn parse(input: &str) -> Result<Chemistry, ParseError> {
+    let mut symbols = vec![];
+    let mut symbol_to_id = FnvHashMap::default();
+    let mut intern = |line: &Line, molecule: &str| -> Result<Molecule, ParseError> {
+        tokenize(line, molecule)?
+            .into_iter()
+            .map(|token| {
+                if let Some(&id) = symbol_to_id.get(token) {
+                    return Ok(id);
+                }
+
+                let id = Element::try_from(symbols.len())
+                    .map_err(|_| line.invalid(token, "Too many distinct elements"))?;
+                symbols.push(token.to_owned());
+                symbol_to_id.insert(token.to_owned(), id);
+                Ok(id)
+            })
+            .collect()
+    };
+
+    let mut input_lines = lines(input).skip_while(|line| line.is_blank());
+    let mut replacements = vec![];
+    for line in input_lines.by_ref().take_while(|line| !line.is_blank()) {
+        let (from, to) = line.split_once(line.text, "=>")?;
+        let [from] = intern(&line, from)?[..] else {
+            return Err(line.invalid(from.trim(), "Replacements start from a single element"));
+        };
+
+        replacements.push((from, intern(&line, to)?));
+    }
+
+    let medicine = match input_lines.find(|line| !line.is_blank()) {
+        Some(line) => intern(&line, line.text)?,
+        None => return Err(missing(input, "the medicine molecule after an empty line")),
+    };
+
+    Ok(Chemistry {
+        symbols,
+        replacements,
+        medicine,
+    })
+}
+
+/// Every molecule reachable from `molecule` with a single replacement
+fn single_replacements(chemistry: &Chemistry, molecule: &[Element]) -> FnvHashSet<Molecule> {
+    let mut molecules = FnvHashSet::default();
+    for (i, element) in molecule.iter().enumerate() {
+        for (_, to) in chemistry
+            .replacements
+            .iter()
+            .filter(|(from, _)| from == element)
+        {
+            molecules.insert(
+                molecule[..i]
+                    .iter()
+                    .chain(to.iter())
+                    .chain(molecule[i + 1..].iter())
+                    .copied()
+                    .collect(),
+            );
+        }
+    }
+
+    molecules
+}
+
+/// Backtracking search from `molecule` back to `e`, reducing the rightmost match first
+/// and trying the longest replacements first at each position.
+///
+/// Returns the number of steps of the first reduction found, which is only the fewest steps
+/// when every reduction has the same length, as with the puzzle's grammar.
+fn reduce(
+    rules: &[&(Element, Molecule)],
+    electron: Element,
+    molecule: &[Element],
+    dead_ends: &mut FnvHashSet<Molecule>,
+) -> Option<usize> {
+    if molecule == [electron] {
+        return Some(0);
+    }
+
+    for &(from, to) in rules {
+        // the electron can only be the starting point
+        if *from == electron && molecule == to.as_slice() {
+            return Some(1);
+        }
+    }
+
+    for start in (0..molecule.len()).rev() {
+        for &(from, to) in rules {
+            if *from == electron || !molecule[start..].starts_with(to) {
+                continue;
+            }
+
+            let reduced = molecule[..start]
+                .iter()
+                .chain(std::iter::once(from))
+                .chain(molecule[start + to.len()..].iter())
+                .copied()
+                .collect_vec();
+
+            if dead_ends.contains(&reduced) {
+                continue;
+            }
+
+            if let Some(steps) = reduce(rules, electron, &reduced, dead_ends) {
+                return Some(steps + 1);
+            }
+
+            dead_ends.insert(reduced);
+        }
+    }
+
+    None
+}
+
+#[aoc(day19, part1)]
+pub fn part1(chemistry: &Chemistry) -> usize {
+    single_replacements(chemistry, &chemistry.medicine).len()
+}
+
+/// Every replacement either adds one element, or has the shape
+/// `X => ?Rn?Ar`, `X => ?Rn?Y?Ar` or `X => ?Rn?Y?Y?Ar`,
+/// where `Rn`, `Y` and `Ar` are never replaced themselves.
+#[aoc(day19, part2, analytic)]
+pub fn part2_analytic(chemistry: &Chemistry) -> Result<usize, Box<dyn Error>> {
+    chemistry
+        .medicine
+        .len()
+        .checked_sub(chemistry.count("Rn"))
+        .and_then(|len| len.checked_sub(chemistry.count("Ar")))
+        .and_then(|len| len.checked_sub(2 * chemistry.count("Y")))
+        .and_then(|len| len.checked_sub(1))
+        .ok_or_else(|| "The grammar does not have the expected shape".into())
+}
+
+/// Only valid for puzzle-shaped grammars, see [`part2_analytic`]: the count of the first
+/// reduction found is returned, it can be more than the fewest steps for other grammars.
+#[aoc(day19, part2, puzzle_reducer)]
+pub fn part2_puzzle_reducer(chemistry: &Chemistry) -> Result<usize, Box<dyn Error>> {
+    let electron = chemistry
+        .element(ELECTRON)
+        .ok_or("No replacement starts from e")?;
+    let rules = chemistry
+        .replacements
+        .iter()
+        .filter(|(_, to)| !to.is_empty())
+        .sorted_by_key(|(_, to)| std::cmp::Reverse(to.len()))
+        .collect_vec();
+
+    Ok(reduce(
+        &rules,
+        electron,
+        &chemistry.medicine,
+        &mut FnvHashSet::default(),
+    )
+    .ok_or("The medicine cannot be made from e")?)
+}
+
+pub const SOLUTIONS: &[&dyn Solution] = &[
+    &Solver::new(19, 1, parse, part1),
+    &Solver::new(19, 2, parse, part2_analytic).named("analytic"),
+    &Solver::new(19, 2, parse, part2_puzzle_reducer).named("puzzle_reducer"),
+];
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::{error::Position, generators};
+
+    const EXAMPLE1: &str = "e => H\ne => O\nH => HO\nH => OH\nO => HH\n\nHOH";
+    const EXAMPLE2: &str = "e => H\ne => O\nH => HO\nH => OH\nO => HH\n\nHOHOHO";
+
+    /// A small grammar shaped like the real inputs
+    const EXAMPLE3: &str = r#"e => HF
+e => NAl
+Al => ThF
+Al => ThRnFAr
+F => CaF
+F => PMg
+F => SiAl
+H => CRnAlAr
+H => CRnFYFYFAr
+H => HCa
+Mg => BF
+F => CRnFYFAr
+
+HCaCRnFYFAr"#;
+
+    #[test]
+    fn tokenize_elements() {
+        let line = Line::new("CRnSiRnCaPTiMgYCaPTiRnFArSiThFAr", 1);
+        assert_eq!(
+            tokenize(&line, line.text).unwrap(),
+            vec![
+                "C", "Rn", "Si", "Rn", "Ca", "P", "Ti", "Mg", "Y", "Ca", "P", "Ti", "Rn", "F",
+                "Ar", "Si", "Th", "F", "Ar"
+            ]
+        );
+        assert_eq!(tokenize(&line, "e").unwrap(), vec!["e"]);
+
+        let line = Line::new("Hx2", 4);
+        assert_eq!(
+            tokenize(&line, line.text).unwrap_err().position(),
+            Position { line: 4, column: 3 }
+        );
+    }
+
+    #[test]
+    fn part1_examples() {
+        assert_eq!(part1(&parse(EXAMPLE1).unwrap()), 4);
+        assert_eq!(part1(&parse(EXAMPLE2).unwrap()), 7);
+    }
+
+    #[test]
+    fn part2_puzzle_reducer_examples() {
+        assert_eq!(part2_puzzle_reducer(&parse(EXAMPLE1).unwrap()).unwrap(), 3);
+        assert_eq!(part2_puzzle_reducer(&parse(EXAMPLE2).unwrap()).unwrap(), 6);
+    }
+
+    #[test]
+    fn part2_analytic_unexpected_shape() {
+        assert!(part2_analytic(&parse("e => H\n\nY").unwrap()).is_err());
+    }
+
+    #[test]
+    fn part2_analytic_matches_reducer() {
+        // e => HF => HCaF => HCaCRnFYFAr
+        let chemistry = parse(EXAMPLE3).unwrap();
+        assert_eq!(part2_puzzle_reducer(&chemistry).unwrap(), 3);
+        assert_eq!(part2_analytic(&chemistry).unwrap(), 3);
+    }
+
+    #[test]
+    fn part2_puzzle_reducer_other_grammar() {
+        // e => H => HHH is shortest, the reducer finds e => H => HH => HHH
+        let chemistry = parse("e => H\nH => HH\nH => HHH\n\nHHH").unwrap();
+        assert_eq!(part2_analytic(&chemistry).unwrap(), 2);
+        assert_eq!(part2_puzzle_reducer(&chemistry).unwrap(), 3);
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(32))]
+
+        #[test]
+        fn analytic_matches_reducer(seed in any::<u64>(), len in 2..60usize) {
+            let chemistry = parse(&generators::day19(&mut generators::seeded(seed), len)).unwrap();
+            prop_assert_eq!(part2_analytic(&chemistry).unwrap(), part2_puzzle_reducer(&chemistry).unwrap());
+        }
+    }
+}
@@ -0,0 +1,193 @@
+#[cfg(not(target_arch = "wasm32"))]
+use rayon::prelude::*;
+
+use crate::{
+    error::{Line, ParseError},
+    solution::{Solution, Solver},
+};
+
+const PRESENTS_PER_ELF_P1: u64 = 10;
+const PRESENTS_PER_ELF_P2: u64 = 11;
+const HOUSES_PER_ELF_P2: usize = 50;
+
+/// Houses handled by one rayon task
+const CHUNK_SIZE: usize = 1 << 14;
+
+type Delivery = fn(usize, u64, Option<usize>) -> Vec<u64>;
+
+/// Presents delivered to every house below `bound`, elf `e` visiting houses `e, 2e, 3e...`
+/// (stopping after `max_houses` houses if given)
+fn deliver(bound: usize, presents_per_elf: u64, max_houses: Option<usize>) -> Vec<u64> {
+    let mut presents = vec![0; bound];
+    for elf in 1..bound {
+        let last_house = max_houses.map_or(bound, |max| (elf * max + 1).min(bound));
+        for house in (elf..last_house).step_by(elf) {
+            presents[house] += elf as u64 * presents_per_elf;
+        }
+    }
+
+    presents
+}
+
+/// Same as `deliver`, with the houses split into chunks filled in parallel
+/// (one after the other on wasm32, browsers have no thread for rayon)
+fn deliver_parallel(bound: usize, presents_per_elf: u64, max_houses: Option<usize>) -> Vec<u64> {
+    let mut presents = vec![0; bound];
+
+    #[cfg(not(target_arch = "wasm32"))]
+    let chunks = presents.par_chunks_mut(CHUNK_SIZE);
+    #[cfg(target_arch = "wasm32")]
+    let chunks = presents.chunks_mut(CHUNK_SIZE);
+
+    chunks.enumerate().for_each(|(chunk_id, chunk)| {
+        let first = chunk_id * CHUNK_SIZE;
+        let end = first + chunk.len();
+
+        // elves that stop early never reach this chunk
+        let first_elf = max_houses.map_or(1, |max| first.div_ceil(max).max(1));
+        for elf in first_elf..end {
+            let last_house = max_houses.map_or(end, |max| (elf * max + 1).min(end));
+            let start = first.div_ceil(elf).max(1) * elf;
+            for house in (start..last_house).step_by(elf) {
+                chunk[house - first] += elf as u64 * presents_per_elf;
+            }
+        }
+    });
+
+    presents
+}
+
+/// Lowest house number getting at least `target` presents.
+///
+/// Elf `h` always visits house `h`, so house `target / presents_per_elf` is an upper bound,
+/// but the answer is usually far below it, so the sieve starts small and doubles as needed.
+fn lowest_house(
+    target: u64,
+    presents_per_elf: u64,
+    max_houses: Option<usize>,
+    deliver: Delivery,
+) -> usize {
+    let upper_bound = target.div_ceil(presents_per_elf).max(1) as usize + 1;
+    let mut bound = (upper_bound / 32).max(16).min(upper_bound);
+
+    loop {
+        let presents = deliver(bound, presents_per_elf, max_houses);
+        if let Some(house) = presents.iter().skip(1).position(|&p| p >= target) {
+            return house + 1;
+        }
+
+        assert!(
+            bound < upper_bound,
+            "house {} should have been enough",
+            upper_bound - 1
+        );
+        bound = (bound * 2).min(upper_bound);
+    }
+}
+
+#[aoc_generator(day20)]
+pub fn parse(input: &str) -> Result<u64, ParseError> {
+    let line = Line::new(input, 1);
+    line.number(line.text)
+}
+
+#[aoc(day20, part1)]
+pub fn part1(target: &u64) -> usize {
+    lowest_house(*target, PRESENTS_PER_ELF_P1, None, deliver)
+}
+
+#[aoc(day20, part1, rayon)]
+pub fn part1_rayon(target: &u64) -> usize {
+    lowest_house(*target, PRESENTS_PER_ELF_P1, None, deliver_parallel)
+}
+
+#[aoc(day20, part2)]
+pub fn part2(target: &u64) -> usize {
+    lowest_house(
+        *target,
+        PRESENTS_PER_ELF_P2,
+        Some(HOUSES_PER_ELF_P2),
+        deliver,
+    )
+}
+
+#[aoc(day20, part2, rayon)]
+pub fn part2_rayon(target: &u64) -> usize {
+    lowest_house(
+        *target,
+        PRESENTS_PER_ELF_P2,
+        Some(HOUSES_PER_ELF_P2),
+        deliver_parallel,
+    )
+}
+
+pub const SOLUTIONS: &[&dyn Solution] = &[
+    &Solver::new(20, 1, parse, part1),
+    &Solver::new(20, 1, parse, part1_rayon).named("rayon"),
+    &Solver::new(20, 2, parse, part2),
+    &Solver::new(20, 2, parse, part2_rayon).named("rayon"),
+];
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn deliver_example1() {
+        assert_eq!(
+            deliver(10, PRESENTS_PER_ELF_P1, None),
+            vec![0, 10, 30, 40, 70, 60, 120, 80, 150, 130]
+        );
+    }
+
+    #[test]
+    fn part1_examples() {
+        assert_eq!(part1(&70), 4);
+        assert_eq!(part1(&130), 8);
+        assert_eq!(part1(&150), 8);
+    }
+
+    #[test]
+    fn parallel_matches_sequential() {
+        let bound = 3 * CHUNK_SIZE + 17;
+        for max_houses in [None, Some(HOUSES_PER_ELF_P2)] {
+            assert_eq!(
+                deliver(bound, PRESENTS_PER_ELF_P2, max_houses),
+                deliver_parallel(bound, PRESENTS_PER_ELF_P2, max_houses)
+            );
+        }
+
+        for target in [1, 70, 5_000, 1_000_000] {
+            assert_eq!(part1(&target), part1_rayon(&target));
+            assert_eq!(part2(&target), part2_rayon(&target));
+        }
+    }
+
+    #[test]
+    fn part2_lazy_elves() {
+        // trial division, keeping only elves that have not stopped yet
+        let presents = |house: usize| -> u64 {
+            (1..=house)
+                .filter(|&elf| house.is_multiple_of(elf) && house / elf <= HOUSES_PER_ELF_P2)
+                .map(|elf| elf as u64 * PRESENTS_PER_ELF_P2)
+                .sum()
+        };
+
+        let delivered = deliver(500, PRESENTS_PER_ELF_P2, Some(HOUSES_PER_ELF_P2));
+        for (house, &count) in delivered.iter().enumerate().skip(1) {
+            assert_eq!(count, presents(house));
+        }
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(16))]
+
+        #[test]
+        fn rayon_matches_sequential(target in 1..2_000_000u64) {
+            prop_assert_eq!(part1(&target), part1_rayon(&target));
+            prop_assert_eq!(part2(&target), part2_rayon(&target));
+        }
+    }
+}
@@ -0,0 +1,205 @@
+use std::str::FromStr;
+
+use itertools::Itertools;
+
+use crate::{
+    error::{lines, missing, ParseError},
+    solution::{Solution, Solver},
+};
+
+const PLAYER_HIT_POINTS: u32 = 100;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Item {
+    name: &'static str,
+    cost: u32,
+    damage: u32,
+    armor: u32,
+}
+
+impl Item {
+    const fn new(name: &'static str, cost: u32, damage: u32, armor: u32) -> Self {
+        Self {
+            name,
+            cost,
+            damage,
+            armor,
+        }
+    }
+}
+
+const WEAPONS: [Item; 5] = [
+    Item::new("Dagger", 8, 4, 0),
+    Item::new("Shortsword", 10, 5, 0),
+    Item::new("Warhammer", 25, 6, 0),
+    Item::new("Longsword", 40, 7, 0),
+    Item::new("Greataxe", 74, 8, 0),
+];
+
+const ARMORS: [Item; 5] = [
+    Item::new("Leather", 13, 0, 1),
+    Item::new("Chainmail", 31, 0, 2),
+    Item::new("Splintmail", 53, 0, 3),
+    Item::new("Bandedmail", 75, 0, 4),
+    Item::new("Platemail", 102, 0, 5),
+];
+
+const RINGS: [Item; 6] = [
+    Item::new("Damage +1", 25, 1, 0),
+    Item::new("Damage +2", 50, 2, 0),
+    Item::new("Damage +3", 100, 3, 0),
+    Item::new("Defense +1", 20, 0, 1),
+    Item::new("Defense +2", 40, 0, 2),
+    Item::new("Defense +3", 80, 0, 3),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Fighter {
+    pub hit_points: u32,
+    pub damage: u32,
+    pub armor: u32,
+}
+
+impl Fighter {
+    /// Number of turns `self` needs to bring `other` down to 0 hit points
+    #[inline]
+    fn turns_to_defeat(&self, other: &Self) -> u32 {
+        let damage_per_turn = self.damage.saturating_sub(other.armor).max(1);
+        other.hit_points.div_ceil(damage_per_turn)
+    }
+
+    /// `self` attacks first, so it wins ties
+    #[inline]
+    fn wins_against(&self, other: &Self) -> bool {
+        self.turns_to_defeat(other) <= other.turns_to_defeat(self)
+    }
+}
+
+impl FromStr for Fighter {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut hit_points = None;
+        let mut damage = None;
+        let mut armor = None;
+        for line in lines(s).filter(|line| !line.is_blank()) {
+            let (stat, value) = line.split_once(line.text, ":")?;
+            let value = line.number(value)?;
+            match stat.trim() {
+                "Hit Points" => hit_points = Some(value),
+                "Damage" => damage = Some(value),
+                "Armor" => armor = Some(value),
+                _ => return Err(line.invalid(stat.trim(), format!("Unknown stat {:?}", stat))),
+            }
+        }
+
+        Ok(Self {
+            hit_points: hit_points.ok_or_else(|| missing(s, "hit points"))?,
+            damage: damage.ok_or_else(|| missing(s, "damage"))?,
+            armor: armor.ok_or_else(|| missing(s, "armor"))?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Loadout {
+    items: Vec<Item>,
+}
+
+impl Loadout {
+    #[inline]
+    fn cost(&self) -> u32 {
+        self.items.iter().map(|item| item.cost).sum()
+    }
+
+    #[inline]
+    fn equip(&self, hit_points: u32) -> Fighter {
+        Fighter {
+            hit_points,
+            damage: self.items.iter().map(|item| item.damage).sum(),
+            armor: self.items.iter().map(|item| item.armor).sum(),
+        }
+    }
+}
+
+/// Exactly one weapon, at most one armor and at most two different rings
+fn loadouts() -> impl Iterator<Item = Loadout> {
+    let armors = std::iter::once(None)
+        .chain(ARMORS.iter().map(Some))
+        .collect_vec();
+    let rings = (0..=2)
+        .flat_map(|count| RINGS.iter().combinations(count))
+        .collect_vec();
+
+    WEAPONS
+        .iter()
+        .cartesian_product(armors)
+        .cartesian_product(rings)
+        .map(|((weapon, armor), rings)| Loadout {
+            items: std::iter::once(weapon)
+                .chain(armor)
+                .chain(rings)
+                .copied()
+                .collect(),
+        })
+}
+
+#[aoc_generator(day21)]
+pub fn parse(input: &str) -> Result<Fighter, ParseError> {
+    input.parse()
+}
+
+#[aoc(day21, part1)]
+pub fn part1(boss: &Fighter) -> Option<u32> {
+    loadouts()
+        .filter(|loadout| loadout.equip(PLAYER_HIT_POINTS).wins_against(boss))
+        .map(|loadout| loadout.cost())
+        .min()
+}
+
+#[aoc(day21, part2)]
+pub fn part2(boss: &Fighter) -> Option<u32> {
+    loadouts()
+        .filter(|loadout| !loadout.equip(PLAYER_HIT_POINTS).wins_against(boss))
+        .map(|loadout| loadout.cost())
+        .max()
+}
+
+pub const SOLUTIONS: &[&dyn Solution] = &[
+    &Solver::new(21, 1, parse, part1),
+    &Solver::new(21, 2, parse, part2),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fight_example1() {
+        let player = Fighter {
+            hit_points: 8,
+            damage: 5,
+            armor: 5,
+        };
+        let boss = parse("Hit Points: 12\nDamage: 7\nArmor: 2").unwrap();
+        assert_eq!(player.turns_to_defeat(&boss), 4);
+        assert_eq!(boss.turns_to_defeat(&player), 4);
+        assert!(player.wins_against(&boss));
+    }
+
+    #[test]
+    fn loadouts_count() {
+        // 5 weapons * 6 armor choices * (1 + 6 + 15) ring choices
+        assert_eq!(loadouts().count(), 5 * 6 * 22);
+        assert!(
+            loadouts().all(|loadout| loadout.items.iter().unique().count() == loadout.items.len())
+        );
+    }
+
+    #[test]
+    fn cheapest_and_priciest() {
+        let boss = parse("Hit Points: 1\nDamage: 0\nArmor: 0").unwrap();
+        assert_eq!(part1(&boss), Some(8));
+        assert_eq!(part2(&boss), None);
+    }
+}
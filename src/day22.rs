@@ -0,0 +1,354 @@
+use std::{cmp::Reverse, collections::BinaryHeap, fmt, str::FromStr};
+
+use fnv::FnvHashMap;
+use itertools::Itertools;
+
+use crate::{
+    error::{lines, missing, ParseError},
+    solution::{Solution, Solver},
+};
+
+const PLAYER_HIT_POINTS: u32 = 50;
+const PLAYER_MANA: u32 = 500;
+
+const SHIELD_ARMOR: u32 = 7;
+const POISON_DAMAGE: u32 = 3;
+const RECHARGE_MANA: u32 = 101;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Spell {
+    MagicMissile,
+    Drain,
+    Shield,
+    Poison,
+    Recharge,
+}
+
+impl Spell {
+    const ALL: [Self; 5] = [
+        Self::MagicMissile,
+        Self::Drain,
+        Self::Shield,
+        Self::Poison,
+        Self::Recharge,
+    ];
+
+    /// Mana needed to cast the spell
+    #[inline]
+    pub const fn cost(self) -> u32 {
+        match self {
+            Self::MagicMissile => 53,
+            Self::Drain => 73,
+            Self::Shield => 113,
+            Self::Poison => 173,
+            Self::Recharge => 229,
+        }
+    }
+}
+
+impl fmt::Display for Spell {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MagicMissile => write!(f, "Magic Missile"),
+            Self::Drain => write!(f, "Drain"),
+            Self::Shield => write!(f, "Shield"),
+            Self::Poison => write!(f, "Poison"),
+            Self::Recharge => write!(f, "Recharge"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Boss {
+    pub hit_points: u32,
+    pub damage: u32,
+}
+
+impl FromStr for Boss {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut hit_points = None;
+        let mut damage = None;
+        for line in lines(s).filter(|line| !line.is_blank()) {
+            let (stat, value) = line.split_once(line.text, ":")?;
+            let value = line.number(value)?;
+            match stat.trim() {
+                "Hit Points" => hit_points = Some(value),
+                "Damage" => damage = Some(value),
+                _ => return Err(line.invalid(stat.trim(), format!("Unknown stat {:?}", stat))),
+            }
+        }
+
+        Ok(Self {
+            hit_points: hit_points.ok_or_else(|| missing(s, "hit points"))?,
+            damage: damage.ok_or_else(|| missing(s, "damage"))?,
+        })
+    }
+}
+
+/// The game at the start of one of the player's turns
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct State {
+    player_hit_points: u32,
+    mana: u32,
+    boss_hit_points: u32,
+    shield_timer: u8,
+    poison_timer: u8,
+    recharge_timer: u8,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Outcome {
+    Won,
+    Lost,
+    Ongoing(State),
+}
+
+impl State {
+    fn new(boss: &Boss, player_hit_points: u32, mana: u32) -> Self {
+        Self {
+            player_hit_points,
+            mana,
+            boss_hit_points: boss.hit_points,
+            shield_timer: 0,
+            poison_timer: 0,
+            recharge_timer: 0,
+        }
+    }
+
+    /// Ticks every active effect, returns the player's armor for this turn
+    #[inline]
+    fn apply_effects(&mut self) -> u32 {
+        let mut armor = 0;
+        if self.shield_timer > 0 {
+            self.shield_timer -= 1;
+            armor = SHIELD_ARMOR;
+        }
+
+        if self.poison_timer > 0 {
+            self.poison_timer -= 1;
+            self.boss_hit_points = self.boss_hit_points.saturating_sub(POISON_DAMAGE);
+        }
+
+        if self.recharge_timer > 0 {
+            self.recharge_timer -= 1;
+            self.mana += RECHARGE_MANA;
+        }
+
+        armor
+    }
+
+    /// Start of the player's turn, before choosing a spell
+    fn start_turn(mut self, hard_mode: bool) -> Outcome {
+        if hard_mode {
+            self.player_hit_points = self.player_hit_points.saturating_sub(1);
+            if self.player_hit_points == 0 {
+                return Outcome::Lost;
+            }
+        }
+
+        self.apply_effects();
+        if self.boss_hit_points == 0 {
+            Outcome::Won
+        } else {
+            Outcome::Ongoing(self)
+        }
+    }
+
+    /// Casts `spell` then plays the boss' turn, `self` must come from `start_turn`.
+    ///
+    /// Returns `None` if `spell` cannot be cast.
+    fn cast(mut self, spell: Spell, boss: &Boss) -> Option<Outcome> {
+        if spell.cost() > self.mana {
+            return None;
+        }
+
+        self.mana -= spell.cost();
+        match spell {
+            Spell::MagicMissile => self.boss_hit_points = self.boss_hit_points.saturating_sub(4),
+            Spell::Drain => {
+                self.boss_hit_points = self.boss_hit_points.saturating_sub(2);
+                self.player_hit_points += 2;
+            }
+            Spell::Shield if self.shield_timer == 0 => self.shield_timer = 6,
+            Spell::Poison if self.poison_timer == 0 => self.poison_timer = 6,
+            Spell::Recharge if self.recharge_timer == 0 => self.recharge_timer = 5,
+            Spell::Shield | Spell::Poison | Spell::Recharge => return None,
+        }
+
+        if self.boss_hit_points == 0 {
+            return Some(Outcome::Won);
+        }
+
+        let armor = self.apply_effects();
+        if self.boss_hit_points == 0 {
+            return Some(Outcome::Won);
+        }
+
+        let damage = boss.damage.saturating_sub(armor).max(1);
+        self.player_hit_points = self.player_hit_points.saturating_sub(damage);
+        if self.player_hit_points == 0 {
+            Some(Outcome::Lost)
+        } else {
+            Some(Outcome::Ongoing(self))
+        }
+    }
+}
+
+/// The cheapest way to win, displayed as `Poison -> Magic Missile (226 mana)`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Victory {
+    pub mana_spent: u32,
+    /// Spells in the order they are cast
+    pub spells: Vec<Spell>,
+}
+
+impl fmt::Display for Victory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} ({} mana)",
+            self.spells.iter().join(" -> "),
+            self.mana_spent
+        )
+    }
+}
+
+/// Dijkstra over game states, weighted by mana spent, `None` if the boss cannot be beaten
+pub fn least_mana_to_win(
+    boss: &Boss,
+    player_hit_points: u32,
+    mana: u32,
+    hard_mode: bool,
+) -> Option<Victory> {
+    let start = State::new(boss, player_hit_points, mana);
+    let mut best_spent = FnvHashMap::from_iter([(start, 0)]);
+    let mut came_from: FnvHashMap<State, (State, Spell)> = FnvHashMap::default();
+    let mut queue = BinaryHeap::from([Reverse((0, start))]);
+    let mut best_win: Option<(u32, State, Option<Spell>)> = None;
+
+    while let Some(Reverse((spent, state))) = queue.pop() {
+        if best_win.is_some_and(|(win_spent, _, _)| win_spent <= spent) {
+            break;
+        }
+
+        if best_spent.get(&state).is_some_and(|&best| best < spent) {
+            continue;
+        }
+
+        let ready = match state.start_turn(hard_mode) {
+            Outcome::Won => {
+                best_win = Some((spent, state, None));
+                break;
+            }
+            Outcome::Lost => continue,
+            Outcome::Ongoing(ready) => ready,
+        };
+
+        for spell in Spell::ALL {
+            let new_spent = spent + spell.cost();
+            match ready.cast(spell, boss) {
+                None | Some(Outcome::Lost) => (),
+                Some(Outcome::Won) => {
+                    if best_win.is_none_or(|(win_spent, _, _)| new_spent < win_spent) {
+                        best_win = Some((new_spent, state, Some(spell)));
+                    }
+                }
+                Some(Outcome::Ongoing(next)) => {
+                    if best_spent.get(&next).is_none_or(|&best| new_spent < best) {
+                        best_spent.insert(next, new_spent);
+                        came_from.insert(next, (state, spell));
+                        queue.push(Reverse((new_spent, next)));
+                    }
+                }
+            }
+        }
+    }
+
+    let (mana_spent, mut state, last_spell) = best_win?;
+    let mut spells: Vec<_> = last_spell.into_iter().collect();
+    while let Some(&(previous, spell)) = came_from.get(&state) {
+        spells.push(spell);
+        state = previous;
+    }
+
+    spells.reverse();
+    Some(Victory { mana_spent, spells })
+}
+
+#[aoc_generator(day22)]
+pub fn parse(input: &str) -> Result<Boss, ParseError> {
+    input.parse()
+}
+
+#[aoc(day22, part1)]
+pub fn part1(boss: &Boss) -> Option<u32> {
+    least_mana_to_win(boss, PLAYER_HIT_POINTS, PLAYER_MANA, false).map(|win| win.mana_spent)
+}
+
+#[aoc(day22, part2)]
+pub fn part2(boss: &Boss) -> Option<u32> {
+    least_mana_to_win(boss, PLAYER_HIT_POINTS, PLAYER_MANA, true).map(|win| win.mana_spent)
+}
+
+pub const SOLUTIONS: &[&dyn Solution] = &[
+    &Solver::new(22, 1, parse, part1),
+    &Solver::new(22, 2, parse, part2),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example1() {
+        let boss = parse("Hit Points: 13\nDamage: 8").unwrap();
+        let victory = least_mana_to_win(&boss, 10, 250, false).unwrap();
+        assert_eq!(victory.mana_spent, 226);
+        assert_eq!(victory.spells, [Spell::Poison, Spell::MagicMissile]);
+        assert_eq!(victory.to_string(), "Poison -> Magic Missile (226 mana)");
+    }
+
+    #[test]
+    fn example2() {
+        let boss = parse("Hit Points: 14\nDamage: 8").unwrap();
+        let victory = least_mana_to_win(&boss, 10, 250, false).unwrap();
+        assert_eq!(victory.mana_spent, 641);
+        assert_eq!(
+            victory.spells.iter().map(|spell| spell.cost()).sum::<u32>(),
+            victory.mana_spent
+        );
+    }
+
+    #[test]
+    fn example2_replay() {
+        let boss = parse("Hit Points: 14\nDamage: 8").unwrap();
+        let mut state = State::new(&boss, 10, 250);
+        let spells = [
+            Spell::Recharge,
+            Spell::Shield,
+            Spell::Drain,
+            Spell::Poison,
+            Spell::MagicMissile,
+        ];
+
+        for (i, spell) in spells.into_iter().enumerate() {
+            let Outcome::Ongoing(ready) = state.start_turn(false) else {
+                panic!("game ended before turn {}", i);
+            };
+
+            match ready.cast(spell, &boss) {
+                Some(Outcome::Ongoing(next)) => state = next,
+                Some(Outcome::Won) => assert_eq!(i, spells.len() - 1),
+                outcome => panic!("unexpected {:?} after casting {}", outcome, spell),
+            }
+        }
+    }
+
+    #[test]
+    fn hard_mode_costs_more() {
+        let boss = parse("Hit Points: 13\nDamage: 8").unwrap();
+        assert!(least_mana_to_win(&boss, 10, 250, true).is_none());
+    }
+}
@@ -0,0 +1,371 @@
+use std::{error::Error, fmt, str::FromStr};
+
+use crate::{
+    error::{parse_lines, Line, ParseError},
+    solution::{Solution, Solver},
+};
+
+/// Way more than the Collatz programs of the puzzle need
+const MAX_STEPS: usize = 1_000_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Register {
+    A,
+    B,
+}
+
+impl Register {
+    fn parse(line: &Line, s: &str) -> Result<Self, ParseError> {
+        match s.trim() {
+            "a" => Ok(Self::A),
+            "b" => Ok(Self::B),
+            other => Err(line.expected(other, r#"register "a" or "b""#)),
+        }
+    }
+}
+
+impl fmt::Display for Register {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::A => write!(f, "a"),
+            Self::B => write!(f, "b"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Instruction {
+    Half(Register),
+    Triple(Register),
+    Increment(Register),
+    Jump(i64),
+    JumpIfEven(Register, i64),
+    JumpIfOne(Register, i64),
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let line = Line::new(s, 1);
+        let (opcode, args) = line.split_once(s.trim(), " ")?;
+
+        let register_and_offset = || -> Result<(Register, i64), Self::Err> {
+            let (register, offset) = line.split_once(args, ",")?;
+            Ok((Register::parse(&line, register)?, line.number(offset)?))
+        };
+
+        match opcode {
+            "hlf" => Ok(Self::Half(Register::parse(&line, args)?)),
+            "tpl" => Ok(Self::Triple(Register::parse(&line, args)?)),
+            "inc" => Ok(Self::Increment(Register::parse(&line, args)?)),
+            "jmp" => Ok(Self::Jump(line.number(args)?)),
+            "jie" => {
+                let (register, offset) = register_and_offset()?;
+                Ok(Self::JumpIfEven(register, offset))
+            }
+            "jio" => {
+                let (register, offset) = register_and_offset()?;
+                Ok(Self::JumpIfOne(register, offset))
+            }
+            _ => Err(line.invalid(opcode, format!("Unknown instruction {:?}", opcode))),
+        }
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Half(register) => write!(f, "hlf {}", register),
+            Self::Triple(register) => write!(f, "tpl {}", register),
+            Self::Increment(register) => write!(f, "inc {}", register),
+            Self::Jump(offset) => write!(f, "jmp {:+}", offset),
+            Self::JumpIfEven(register, offset) => write!(f, "jie {}, {:+}", register, offset),
+            Self::JumpIfOne(register, offset) => write!(f, "jio {}, {:+}", register, offset),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VmError {
+    StepLimitExceeded {
+        steps: usize,
+        pc: usize,
+    },
+    /// `tpl` or `inc` made a register larger than `u64::MAX`
+    Overflow {
+        pc: usize,
+        register: Register,
+    },
+}
+
+impl fmt::Display for VmError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::StepLimitExceeded { steps, pc } => write!(
+                f,
+                "Program still running after {} steps (at instruction {})",
+                steps, pc
+            ),
+            Self::Overflow { pc, register } => {
+                write!(f, "Register {} overflowed at instruction {}", register, pc)
+            }
+        }
+    }
+}
+
+impl Error for VmError {}
+
+/// One executed instruction, with the registers as they were after it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TraceStep {
+    pub pc: usize,
+    pub instruction: Instruction,
+    pub a: u64,
+    pub b: u64,
+}
+
+impl fmt::Display for TraceStep {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:>3}: {:<12} a = {}, b = {}",
+            self.pc,
+            self.instruction.to_string(),
+            self.a,
+            self.b
+        )
+    }
+}
+
+/// The computer of the puzzle, stopping after `MAX_STEPS` steps unless told otherwise
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Vm {
+    a: u64,
+    b: u64,
+    pc: usize,
+    steps: usize,
+    max_steps: usize,
+    trace: Option<Vec<TraceStep>>,
+}
+
+impl Vm {
+    /// A computer starting with `a` in register a and 0 in register b
+    pub fn new(a: u64) -> Self {
+        Self {
+            a,
+            b: 0,
+            pc: 0,
+            steps: 0,
+            max_steps: MAX_STEPS,
+            trace: None,
+        }
+    }
+
+    pub fn with_max_steps(mut self, max_steps: usize) -> Self {
+        self.max_steps = max_steps;
+        self
+    }
+
+    /// Records every executed instruction, see `trace`
+    pub fn with_trace(mut self) -> Self {
+        self.trace = Some(vec![]);
+        self
+    }
+
+    pub fn a(&self) -> u64 {
+        self.a
+    }
+
+    pub fn b(&self) -> u64 {
+        self.b
+    }
+
+    /// Number of instructions executed so far
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    /// The executed instructions, `None` unless built `with_trace`
+    pub fn trace(&self) -> Option<&[TraceStep]> {
+        self.trace.as_deref()
+    }
+
+    #[inline]
+    fn register(&mut self, register: Register) -> &mut u64 {
+        match register {
+            Register::A => &mut self.a,
+            Register::B => &mut self.b,
+        }
+    }
+
+    /// Replaces `register` with `op` of its value, `op` returns `None` on overflow
+    #[inline]
+    fn update(&mut self, register: Register, op: fn(u64) -> Option<u64>) -> Result<(), VmError> {
+        let pc = self.pc;
+        let value = self.register(register);
+        *value = op(*value).ok_or(VmError::Overflow { pc, register })?;
+        self.pc += 1;
+        Ok(())
+    }
+
+    #[inline]
+    fn jump(&mut self, offset: i64) {
+        // jumping before the start ends the program just like jumping past the end
+        self.pc = self
+            .pc
+            .checked_add_signed(offset as isize)
+            .unwrap_or(usize::MAX);
+    }
+
+    /// Runs until the program counter leaves the program
+    pub fn run(&mut self, program: &[Instruction]) -> Result<(), VmError> {
+        while let Some(&instruction) = program.get(self.pc) {
+            if self.steps >= self.max_steps {
+                return Err(VmError::StepLimitExceeded {
+                    steps: self.steps,
+                    pc: self.pc,
+                });
+            }
+
+            let pc = self.pc;
+            match instruction {
+                Instruction::Half(register) => {
+                    *self.register(register) /= 2;
+                    self.pc += 1;
+                }
+                Instruction::Triple(register) => {
+                    self.update(register, |value| value.checked_mul(3))?
+                }
+                Instruction::Increment(register) => {
+                    self.update(register, |value| value.checked_add(1))?
+                }
+                Instruction::Jump(offset) => self.jump(offset),
+                Instruction::JumpIfEven(register, offset) => {
+                    if self.register(register).is_multiple_of(2) {
+                        self.jump(offset);
+                    } else {
+                        self.pc += 1;
+                    }
+                }
+                Instruction::JumpIfOne(register, offset) => {
+                    if *self.register(register) == 1 {
+                        self.jump(offset);
+                    } else {
+                        self.pc += 1;
+                    }
+                }
+            }
+
+            self.steps += 1;
+            if let Some(trace) = &mut self.trace {
+                trace.push(TraceStep {
+                    pc,
+                    instruction,
+                    a: self.a,
+                    b: self.b,
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[aoc_generator(day23)]
+pub fn parse(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    parse_lines(input)
+}
+
+#[aoc(day23, part1)]
+pub fn part1(program: &[Instruction]) -> Result<u64, VmError> {
+    let mut vm = Vm::new(0);
+    vm.run(program)?;
+    Ok(vm.b)
+}
+
+#[aoc(day23, part2)]
+pub fn part2(program: &[Instruction]) -> Result<u64, VmError> {
+    let mut vm = Vm::new(1);
+    vm.run(program)?;
+    Ok(vm.b)
+}
+
+pub const SOLUTIONS: &[&dyn Solution] = &[
+    &Solver::new(23, 1, parse, |program| part1(program)),
+    &Solver::new(23, 2, parse, |program| part2(program)),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE1: &str = "inc a\njio a, +2\ntpl a\ninc a";
+
+    /// Counts the Collatz steps of `a` into `b`
+    const COLLATZ: &str = r#"jio a, +8
+inc b
+jie a, +4
+tpl a
+inc a
+jmp +2
+hlf a
+jmp -7"#;
+
+    #[test]
+    fn part1_example1() {
+        let mut vm = Vm::new(0);
+        vm.run(&parse(EXAMPLE1).unwrap()).unwrap();
+        assert_eq!(vm.a, 2);
+    }
+
+    #[test]
+    fn collatz() {
+        let program = parse(COLLATZ).unwrap();
+        for (start, steps) in [(1, 0), (6, 8), (27, 111)] {
+            let mut vm = Vm::new(start);
+            vm.run(&program).unwrap();
+            assert_eq!(vm.b, steps);
+        }
+    }
+
+    #[test]
+    fn trace_example1() {
+        let mut vm = Vm::new(0).with_trace();
+        vm.run(&parse(EXAMPLE1).unwrap()).unwrap();
+        assert_eq!(vm.steps(), 3);
+        let trace = vm.trace().unwrap();
+        assert_eq!(
+            trace
+                .iter()
+                .map(|step| (step.pc, step.a))
+                .collect::<Vec<_>>(),
+            [(0, 1), (1, 1), (3, 2)]
+        );
+        assert_eq!(trace[1].to_string(), "  1: jio a, +2    a = 1, b = 0");
+    }
+
+    #[test]
+    fn runaway_program() {
+        let program = parse("inc a\njmp -1").unwrap();
+        assert_eq!(
+            Vm::new(0).with_max_steps(100).run(&program),
+            Err(VmError::StepLimitExceeded { steps: 100, pc: 0 })
+        );
+    }
+
+    #[test]
+    fn overflow() {
+        let program = parse("inc a\ntpl a\njmp -1").unwrap();
+        let err = Vm::new(0).run(&program).unwrap_err();
+        assert_eq!(
+            err,
+            VmError::Overflow {
+                pc: 1,
+                register: Register::A
+            }
+        );
+        assert_eq!(err.to_string(), "Register a overflowed at instruction 1");
+        assert_eq!(part1(&program), Err(err));
+    }
+}
@@ -0,0 +1,113 @@
+use itertools::Itertools;
+
+use crate::{
+    error::{parse_numbers, ParseError},
+    solution::{Solution, Solver},
+};
+
+/// Indices of every subset of `weights` summing to `target`, smallest subsets first,
+/// each subset in increasing index order
+pub fn subsets_by_size(weights: &[u64], target: u64) -> impl Iterator<Item = Vec<usize>> + '_ {
+    (1..=weights.len()).flat_map(move |size| {
+        (0..weights.len())
+            .combinations(size)
+            .filter(move |indices| indices.iter().map(|&i| weights[i]).sum::<u64>() == target)
+    })
+}
+
+#[inline]
+fn without(weights: &[u64], indices: &[usize]) -> Vec<u64> {
+    weights
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| !indices.contains(i))
+        .map(|(_, &weight)| weight)
+        .collect()
+}
+
+/// Whether `weights` can be split into `groups` groups weighing `target` each
+fn can_split(weights: &[u64], groups: usize, target: u64) -> bool {
+    match groups {
+        0 => weights.is_empty(),
+        1 => weights.iter().sum::<u64>() == target,
+        _ => subsets_by_size(weights, target)
+            .any(|indices| can_split(&without(weights, &indices), groups - 1, target)),
+    }
+}
+
+#[inline]
+fn quantum_entanglement(weights: &[u64], indices: &[usize]) -> u64 {
+    indices.iter().map(|&i| weights[i]).product()
+}
+
+/// Smallest quantum entanglement among the smallest first groups
+/// that still let the remaining packages be split evenly
+fn best_first_group(weights: &[u64], groups: usize) -> Option<u64> {
+    let total: u64 = weights.iter().sum();
+    if groups == 0 || !total.is_multiple_of(groups as u64) {
+        return None;
+    }
+
+    let target = total / groups as u64;
+    let mut candidates = subsets_by_size(weights, target).peekable();
+    while let Some(first) = candidates.peek() {
+        let size = first.len();
+        let best = candidates
+            .peeking_take_while(|indices| indices.len() == size)
+            .sorted_by_key(|indices| quantum_entanglement(weights, indices))
+            .find(|indices| can_split(&without(weights, indices), groups - 1, target))
+            .map(|indices| quantum_entanglement(weights, &indices));
+
+        if best.is_some() {
+            return best;
+        }
+    }
+
+    None
+}
+
+#[aoc_generator(day24)]
+pub fn parse(input: &str) -> Result<Vec<u64>, ParseError> {
+    parse_numbers(input)
+}
+
+#[aoc(day24, part1)]
+pub fn part1(weights: &[u64]) -> Option<u64> {
+    best_first_group(weights, 3)
+}
+
+#[aoc(day24, part2)]
+pub fn part2(weights: &[u64]) -> Option<u64> {
+    best_first_group(weights, 4)
+}
+
+pub const SOLUTIONS: &[&dyn Solution] = &[
+    &Solver::new(24, 1, parse, |weights| part1(weights)),
+    &Solver::new(24, 2, parse, |weights| part2(weights)),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE1: &str = "1\n2\n3\n4\n5\n7\n8\n9\n10\n11";
+
+    #[test]
+    fn part1_example1() {
+        assert_eq!(part1(&parse(EXAMPLE1).unwrap()), Some(99));
+    }
+
+    #[test]
+    fn part2_example1() {
+        assert_eq!(part2(&parse(EXAMPLE1).unwrap()), Some(44));
+    }
+
+    #[test]
+    fn remaining_must_split() {
+        // {10, 17, 24} has the lowest quantum entanglement of the smallest groups,
+        // but the other packages cannot be split in two afterwards
+        let weights = [10, 11, 11, 16, 17, 20, 21, 23, 24];
+        assert!(!can_split(&[11, 11, 16, 20, 21, 23], 2, 51));
+        assert_eq!(best_first_group(&weights, 3), Some(10 * 20 * 21));
+    }
+}
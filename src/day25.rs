@@ -0,0 +1,160 @@
+use std::fmt::Write;
+
+use crate::{
+    error::{Line, ParseError},
+    solution::{Solution, Solver},
+};
+
+const FIRST_CODE: u64 = 20151125;
+const MULTIPLIER: u64 = 252533;
+const MODULUS: u64 = 33554393;
+
+/// `(base ^ exponent) % modulus` by repeated squaring
+#[inline]
+fn mod_pow(mut base: u64, mut exponent: u64, modulus: u64) -> u64 {
+    let mut result = 1 % modulus;
+    base %= modulus;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = result * base % modulus;
+        }
+
+        base = base * base % modulus;
+        exponent >>= 1;
+    }
+
+    result
+}
+
+/// The modulus is prime, so by Fermat's little theorem the powers of the multiplier
+/// repeat every `MODULUS - 1` codes
+const PERIOD: u128 = MODULUS as u128 - 1;
+
+/// Position of `(row, col)` (both starting at 1) in the order codes are generated, starting at 0,
+/// modulo [`PERIOD`] so that any coordinates fit
+#[inline]
+fn diagonal_index(row: u64, col: u64) -> u64 {
+    let diagonal = row as u128 + col as u128 - 1;
+    let (even, odd) = if diagonal.is_multiple_of(2) {
+        (diagonal, diagonal - 1)
+    } else {
+        (diagonal - 1, diagonal)
+    };
+    let triangle = (even / 2 % PERIOD) * (odd % PERIOD) % PERIOD;
+
+    ((triangle + col as u128 - 1) % PERIOD) as u64
+}
+
+#[inline]
+fn code_at(row: u64, col: u64) -> u64 {
+    FIRST_CODE * mod_pow(MULTIPLIER, diagonal_index(row, col), MODULUS) % MODULUS
+}
+
+/// The top-left `size` x `size` corner of the code table, laid out like the manual
+pub fn format_corner(size: u64) -> String {
+    let mut table = String::from("   |");
+    for col in 1..=size {
+        write!(table, " {:>9}", col).unwrap();
+    }
+
+    table.push_str("\n---+");
+    for _ in 1..=size {
+        table.push_str("----------");
+    }
+
+    for row in 1..=size {
+        write!(table, "\n{:>2} |", row).unwrap();
+        for col in 1..=size {
+            write!(table, " {:>9}", code_at(row, col)).unwrap();
+        }
+    }
+
+    table
+}
+
+/// `To continue, please consult the code grid in the manual.  Enter the code at row 2947, column 3029.`
+#[aoc_generator(day25)]
+pub fn parse(input: &str) -> Result<(u64, u64), ParseError> {
+    let line = Line::new(input, 1);
+    let (_, rest) = line.split_once(input, "row ")?;
+    let (row_text, rest) = line.split_once(rest, ", column ")?;
+    let col_text = rest.trim().trim_end_matches('.');
+
+    let (row, col): (u64, u64) = (line.number(row_text)?, line.number(col_text)?);
+    if row == 0 {
+        return Err(line.invalid(row_text.trim(), "Rows start at 1"));
+    }
+
+    if col == 0 {
+        return Err(line.invalid(col_text, "Columns start at 1"));
+    }
+
+    Ok((row, col))
+}
+
+#[aoc(day25, part1)]
+pub fn part1(&(row, col): &(u64, u64)) -> u64 {
+    code_at(row, col)
+}
+
+pub const SOLUTIONS: &[&dyn Solution] = &[&Solver::new(25, 1, parse, part1)];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CORNER: [[u64; 6]; 6] = [
+        [20151125, 18749137, 17289845, 30943339, 10071777, 33511524],
+        [31916031, 21629792, 16929656, 7726640, 15514188, 4041754],
+        [16080970, 8057251, 1601130, 7981243, 11661866, 16474243],
+        [24592653, 32451966, 21345942, 9380097, 10600672, 31527494],
+        [77061, 17552253, 28094349, 6899651, 9250759, 31663883],
+        [33071741, 6796745, 25397450, 24659492, 1534922, 27995004],
+    ];
+
+    #[test]
+    fn diagonal_order() {
+        assert_eq!(diagonal_index(1, 1), 0);
+        assert_eq!(diagonal_index(2, 1), 1);
+        assert_eq!(diagonal_index(1, 2), 2);
+        assert_eq!(diagonal_index(4, 2), 11);
+        assert_eq!(diagonal_index(1, 5), 14);
+    }
+
+    #[test]
+    fn huge_coordinates() {
+        assert_eq!(diagonal_index(4294967295, 4294967295), 18843692);
+        assert_eq!(code_at(4294967295, 4294967295), 16677280);
+        assert_eq!(code_at(u64::MAX, u64::MAX), 7982616);
+    }
+
+    #[test]
+    fn corner_example() {
+        for (row, codes) in CORNER.iter().enumerate() {
+            for (col, &code) in codes.iter().enumerate() {
+                assert_eq!(code_at(row as u64 + 1, col as u64 + 1), code);
+            }
+        }
+    }
+
+    #[test]
+    fn corner_format() {
+        let table = format_corner(6);
+        let lines: Vec<_> = table.lines().collect();
+        assert_eq!(lines.len(), 8);
+        assert_eq!(
+            lines[2],
+            " 1 |  20151125  18749137  17289845  30943339  10071777  33511524"
+        );
+        assert_eq!(
+            lines[6],
+            " 5 |     77061  17552253  28094349   6899651   9250759  31663883"
+        );
+    }
+
+    #[test]
+    fn part1_example1() {
+        let input = "To continue, please consult the code grid in the manual.  Enter the code at row 6, column 5.";
+        assert_eq!(part1(&parse(input).unwrap()), 1534922);
+    }
+}
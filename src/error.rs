@@ -0,0 +1,358 @@
+use std::{
+    error::Error,
+    fmt,
+    io::{self, BufRead},
+    str::FromStr,
+};
+
+/// Where a `ParseError` happened, lines and columns start at 1
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Position {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl fmt::Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)
+    }
+}
+
+/// Error returned by every `aoc_generator` when the puzzle input is malformed
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ParseError {
+    /// A delimiter, keyword or value was not where it should be
+    Expected {
+        position: Position,
+        expected: String,
+    },
+    /// A number could not be parsed
+    InvalidNumber {
+        position: Position,
+        text: String,
+        reason: String,
+    },
+    /// A character that has no meaning at this point of the input
+    UnexpectedCharacter { position: Position, found: char },
+    /// The input is well formed but makes no sense for the puzzle
+    Invalid { position: Position, message: String },
+}
+
+impl ParseError {
+    pub fn position(&self) -> Position {
+        match self {
+            Self::Expected { position, .. }
+            | Self::InvalidNumber { position, .. }
+            | Self::UnexpectedCharacter { position, .. }
+            | Self::Invalid { position, .. } => *position,
+        }
+    }
+
+    fn position_mut(&mut self) -> &mut Position {
+        match self {
+            Self::Expected { position, .. }
+            | Self::InvalidNumber { position, .. }
+            | Self::UnexpectedCharacter { position, .. }
+            | Self::Invalid { position, .. } => position,
+        }
+    }
+
+    /// Moves an error produced while parsing a single line (e.g. in a `FromStr` impl)
+    /// to the line it actually came from
+    pub fn on_line(mut self, line: usize) -> Self {
+        self.position_mut().line = line;
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Expected { position, expected } => {
+                write!(f, "{}: expected {}", position, expected)
+            }
+            Self::InvalidNumber {
+                position,
+                text,
+                reason,
+            } => write!(f, "{}: invalid number {:?} ({})", position, text, reason),
+            Self::UnexpectedCharacter { position, found } => {
+                write!(f, "{}: unexpected character {:?}", position, found)
+            }
+            Self::Invalid { position, message } => write!(f, "{}: {}", position, message),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// Error of the streaming entry points, which read their input as they parse it
+#[derive(Debug)]
+pub enum ReadError {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl fmt::Display for ReadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "could not read the input: {}", err),
+            Self::Parse(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl Error for ReadError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            Self::Parse(err) => Some(err),
+        }
+    }
+}
+
+impl From<io::Error> for ReadError {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+impl From<ParseError> for ReadError {
+    fn from(err: ParseError) -> Self {
+        Self::Parse(err)
+    }
+}
+
+/// A line of the puzzle input, used to give positions to parse errors.
+///
+/// Every `&str` given to its methods must be a slice of `text`,
+/// otherwise errors point at the start of the line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) struct Line<'a> {
+    pub(crate) text: &'a str,
+    pub(crate) number: usize,
+}
+
+impl<'a> Line<'a> {
+    pub(crate) fn new(text: &'a str, number: usize) -> Self {
+        Self { text, number }
+    }
+
+    pub(crate) fn position(&self, at: &str) -> Position {
+        let offset = (at.as_ptr() as usize).wrapping_sub(self.text.as_ptr() as usize);
+        let column = if offset <= self.text.len() && self.text.is_char_boundary(offset) {
+            self.text[..offset].chars().count() + 1
+        } else {
+            1
+        };
+
+        Position {
+            line: self.number,
+            column,
+        }
+    }
+
+    pub(crate) fn expected(&self, at: &str, expected: impl Into<String>) -> ParseError {
+        ParseError::Expected {
+            position: self.position(at),
+            expected: expected.into(),
+        }
+    }
+
+    pub(crate) fn invalid(&self, at: &str, message: impl Into<String>) -> ParseError {
+        ParseError::Invalid {
+            position: self.position(at),
+            message: message.into(),
+        }
+    }
+
+    /// `at` must start with `found`
+    pub(crate) fn unexpected(&self, at: &str, found: char) -> ParseError {
+        ParseError::UnexpectedCharacter {
+            position: self.position(at),
+            found,
+        }
+    }
+
+    pub(crate) fn split_once(
+        &self,
+        part: &'a str,
+        delimiter: &str,
+    ) -> Result<(&'a str, &'a str), ParseError> {
+        part.split_once(delimiter)
+            .ok_or_else(|| self.expected(part, format!("{:?}", delimiter)))
+    }
+
+    pub(crate) fn strip_prefix(&self, part: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+        part.strip_prefix(prefix)
+            .ok_or_else(|| self.expected(part, format!("{:?}", prefix)))
+    }
+
+    pub(crate) fn strip_suffix(&self, part: &'a str, suffix: &str) -> Result<&'a str, ParseError> {
+        part.strip_suffix(suffix).ok_or_else(|| {
+            self.expected(
+                &part[part.len().saturating_sub(suffix.len())..],
+                format!("{:?}", suffix),
+            )
+        })
+    }
+
+    /// Parses `part` once trimmed
+    pub(crate) fn number<T>(&self, part: &'a str) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        let part = part.trim();
+        part.parse()
+            .map_err(|err: T::Err| ParseError::InvalidNumber {
+                position: self.position(part),
+                text: part.to_owned(),
+                reason: err.to_string(),
+            })
+    }
+
+    /// Parses the whole line with its `FromStr` impl
+    pub(crate) fn parse<T>(&self) -> Result<T, ParseError>
+    where
+        T: FromStr<Err = ParseError>,
+    {
+        self.text
+            .parse()
+            .map_err(|err: ParseError| err.on_line(self.number))
+    }
+
+    #[inline]
+    pub(crate) fn is_blank(&self) -> bool {
+        self.text.trim().is_empty()
+    }
+}
+
+/// Every line of `input`, numbered from 1
+pub(crate) fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, text)| Line::new(text, i + 1))
+}
+
+/// Something that should have come after the last line of `input`
+pub(crate) fn missing(input: &str, expected: impl Into<String>) -> ParseError {
+    ParseError::Expected {
+        position: Position {
+            line: input.lines().count() + 1,
+            column: 1,
+        },
+        expected: expected.into(),
+    }
+}
+
+/// Parses every non blank line of `input` with its `FromStr` impl
+pub(crate) fn parse_lines<T>(input: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr<Err = ParseError>,
+{
+    lines(input)
+        .filter(|line| !line.is_blank())
+        .map(|line| line.parse())
+        .collect()
+}
+
+/// Same as `parse_lines`, one line at a time, so `reader` never has to fit in memory
+pub fn read_lines<T, R>(reader: R) -> impl Iterator<Item = Result<T, ReadError>>
+where
+    T: FromStr<Err = ParseError>,
+    R: BufRead,
+{
+    reader
+        .lines()
+        .enumerate()
+        .filter_map(|(i, text)| match text {
+            Ok(text) => {
+                let line = Line::new(&text, i + 1);
+                (!line.is_blank()).then(|| line.parse().map_err(ReadError::from))
+            }
+            Err(err) => Some(Err(err.into())),
+        })
+}
+
+/// Parses every non blank line of `input` as a single number
+pub(crate) fn parse_numbers<T>(input: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    lines(input)
+        .filter(|line| !line.is_blank())
+        .map(|line| line.number(line.text))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn positions() {
+        let line = Line::new("Alice can fly 14 km/s", 3);
+        let (_, rest) = line.split_once(line.text, " can fly ").unwrap();
+        assert_eq!(
+            line.position(rest),
+            Position {
+                line: 3,
+                column: 15
+            }
+        );
+
+        let err = line.number::<u32>(&rest[..6]).unwrap_err();
+        assert_eq!(
+            err.position(),
+            Position {
+                line: 3,
+                column: 15
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            r#"line 3, column 15: invalid number "14 km/" (invalid digit found in string)"#
+        );
+
+        let err = line.split_once(rest, "=").unwrap_err();
+        assert_eq!(
+            err.position(),
+            Position {
+                line: 3,
+                column: 15
+            }
+        );
+        assert_eq!(err.on_line(7).position().line, 7);
+    }
+
+    #[test]
+    fn read_numbered_lines() {
+        #[derive(Debug, PartialEq)]
+        struct Number(u32);
+
+        impl FromStr for Number {
+            type Err = ParseError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let line = Line::new(s, 1);
+                line.number(s).map(Number)
+            }
+        }
+
+        let numbers: Vec<_> = read_lines::<Number, _>("1\r\n\r\n2\n".as_bytes())
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(numbers, [Number(1), Number(2)]);
+
+        let err = read_lines::<Number, _>("1\n\nx\n".as_bytes())
+            .find_map(Result::err)
+            .unwrap();
+        match err {
+            ReadError::Parse(err) => assert_eq!(err.position(), Position { line: 3, column: 1 }),
+            ReadError::Io(err) => panic!("{}", err),
+        }
+    }
+}
@@ -0,0 +1,617 @@
+//! Seeded synthetic puzzle inputs shaped like the real ones, for benchmarks and
+//! property tests that cannot ship anyone's personal input.
+//!
+//! Days whose inputs are lists take their length, so the same generators can
+//! produce inputs far larger than the real ones.
+
+use std::{collections::BTreeSet, fmt::Write, ops::Range};
+
+use rand::{seq::SliceRandom, Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use serde_json::{Map, Value};
+
+use crate::day16::MFCSAM_READOUT;
+
+const LOWERCASE: &[u8] = b"abcdefghijklmnopqrstuvwxyz";
+
+fn word<R: Rng>(rng: &mut R, len: usize) -> String {
+    (0..len)
+        .map(|_| *LOWERCASE.choose(rng).unwrap() as char)
+        .collect()
+}
+
+/// `count` different words, in random order
+fn unique_words<R: Rng>(rng: &mut R, count: usize, lengths: Range<usize>) -> Vec<String> {
+    let mut words = BTreeSet::new();
+    while words.len() < count {
+        let len = rng.gen_range(lengths.clone());
+        words.insert(word(rng, len));
+    }
+
+    let mut words: Vec<_> = words.into_iter().collect();
+    words.shuffle(rng);
+    words
+}
+
+/// `count` different capitalized names, for cities, guests and reindeer
+fn names<R: Rng>(rng: &mut R, count: usize) -> Vec<String> {
+    unique_words(rng, count, 4..10)
+        .into_iter()
+        .map(|name| name[..1].to_uppercase() + &name[1..])
+        .collect()
+}
+
+/// Every input is written with `writeln!`, without the trailing newline `cargo aoc` strips
+fn lines(mut text: String) -> String {
+    text.truncate(text.trim_end().len());
+    text
+}
+
+/// The random number generator behind every input, so the same seed gives the same input everywhere
+pub fn seeded(seed: u64) -> ChaCha8Rng {
+    ChaCha8Rng::seed_from_u64(seed)
+}
+
+/// The input of `day` generated from `seed`, as large as the real one
+pub fn input(day: u32, seed: u64) -> Option<String> {
+    let rng = &mut seeded(seed);
+    let input = match day {
+        1 => day01(rng, 7000),
+        2 => day02(rng, 1000),
+        3 => day03(rng, 8192),
+        4 => day04(rng),
+        5 => day05(rng, 1000),
+        6 => day06(rng, 300),
+        7 => day07(rng, 340),
+        8 => day08(rng, 300),
+        9 => day09(rng, 8),
+        10 => day10(rng),
+        11 => day11(rng),
+        12 => day12(rng),
+        13 => day13(rng, 8),
+        14 => day14(rng, 9),
+        15 => day15(rng),
+        16 => day16(rng, 500),
+        17 => day17(rng, 20),
+        18 => day18(rng, 100),
+        19 => day19(rng, 290),
+        20 => day20(rng),
+        21 => day21(rng),
+        22 => day22(rng),
+        23 => day23(rng),
+        24 => day24(rng),
+        25 => day25(rng),
+        _ => return None,
+    };
+
+    Some(input)
+}
+
+/// `len` parentheses, followed by enough `)` to reach the basement
+pub fn day01<R: Rng>(rng: &mut R, len: usize) -> String {
+    let mut floor = 0i64;
+    let mut lowest = 0;
+    let mut input: String = (0..len)
+        .map(|_| {
+            let up = rng.gen_bool(0.51);
+            floor += if up { 1 } else { -1 };
+            lowest = lowest.min(floor);
+            if up {
+                '('
+            } else {
+                ')'
+            }
+        })
+        .collect();
+
+    if lowest >= 0 {
+        input.extend(std::iter::repeat_n(')', floor as usize + 1));
+    }
+
+    input
+}
+
+pub fn day02<R: Rng>(rng: &mut R, gifts: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..gifts {
+        let [l, w, h] = [(); 3].map(|()| rng.gen_range(1..=30));
+        writeln!(input, "{}x{}x{}", l, w, h).unwrap();
+    }
+
+    lines(input)
+}
+
+pub fn day03<R: Rng>(rng: &mut R, moves: usize) -> String {
+    (0..moves)
+        .map(|_| *b"^v<>".choose(rng).unwrap() as char)
+        .collect()
+}
+
+pub fn day04<R: Rng>(rng: &mut R) -> String {
+    word(rng, 8)
+}
+
+pub fn day05<R: Rng>(rng: &mut R, strings: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..strings {
+        writeln!(input, "{}", word(rng, 16)).unwrap();
+    }
+
+    lines(input)
+}
+
+pub fn day06<R: Rng>(rng: &mut R, instructions: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..instructions {
+        let kind = ["turn on", "turn off", "toggle"].choose(rng).unwrap();
+        let mut corners = [(); 4].map(|()| rng.gen_range(0..1000));
+        if corners[0] > corners[2] {
+            corners.swap(0, 2);
+        }
+
+        if corners[1] > corners[3] {
+            corners.swap(1, 3);
+        }
+
+        let [x1, y1, x2, y2] = corners;
+        writeln!(input, "{} {},{} through {},{}", kind, x1, y1, x2, y2).unwrap();
+    }
+
+    lines(input)
+}
+
+/// An acyclic circuit of `gates` gates, each reading wires defined before it,
+/// with `b` as an input and `a` as the output
+pub fn day07<R: Rng>(rng: &mut R, gates: usize) -> String {
+    let names = unique_words(rng, gates, 2..5);
+    let mut defined = vec!["b".to_owned()];
+    let mut circuit = vec![format!("{} -> b", rng.gen::<u16>())];
+    for name in names {
+        let x = defined.choose(rng).unwrap();
+        let y = defined.choose(rng).unwrap();
+        let gate = match rng.gen_range(0..7) {
+            0 => format!("{} AND {}", x, y),
+            1 => format!("1 AND {}", x),
+            2 => format!("{} OR {}", x, y),
+            3 => format!("NOT {}", x),
+            4 => format!("{} LSHIFT {}", x, rng.gen_range(1..16)),
+            5 => format!("{} RSHIFT {}", x, rng.gen_range(1..16)),
+            _ => rng.gen::<u16>().to_string(),
+        };
+
+        circuit.push(format!("{} -> {}", gate, name));
+        defined.push(name);
+    }
+
+    circuit.push(format!("{} -> a", defined.last().unwrap()));
+    circuit.shuffle(rng);
+    circuit.join("\n")
+}
+
+pub fn day08<R: Rng>(rng: &mut R, strings: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..strings {
+        input.push('"');
+        for _ in 0..rng.gen_range(0..30) {
+            match rng.gen_range(0..10) {
+                0 => input.push_str(r"\\"),
+                1 => input.push_str(r#"\""#),
+                2 => write!(input, r"\x{:02x}", rng.gen::<u8>()).unwrap(),
+                _ => input.push(*LOWERCASE.choose(rng).unwrap() as char),
+            }
+        }
+
+        input.push_str("\"\n");
+    }
+
+    lines(input)
+}
+
+/// A complete graph of `cities` cities
+pub fn day09<R: Rng>(rng: &mut R, cities: usize) -> String {
+    let cities = names(rng, cities);
+    let mut input = String::new();
+    for (i, from) in cities.iter().enumerate() {
+        for to in &cities[i + 1..] {
+            writeln!(input, "{} to {} = {}", from, to, rng.gen_range(10..150)).unwrap();
+        }
+    }
+
+    lines(input)
+}
+
+/// Ten digits without a run longer than 3, like every real input
+pub fn day10<R: Rng>(rng: &mut R) -> String {
+    let mut digits: Vec<u8> = vec![];
+    while digits.len() < 10 {
+        let digit = rng.gen_range(b'1'..=b'3');
+        if !digits.ends_with(&[digit; 3]) {
+            digits.push(digit);
+        }
+    }
+
+    String::from_utf8(digits).unwrap()
+}
+
+/// The real inputs never contain `i`, `o` or `l`
+pub fn day11<R: Rng>(rng: &mut R) -> String {
+    let letters: Vec<u8> = LOWERCASE
+        .iter()
+        .copied()
+        .filter(|letter| !b"iol".contains(letter))
+        .collect();
+
+    (0..8)
+        .map(|_| *letters.choose(rng).unwrap() as char)
+        .collect()
+}
+
+fn json_value<R: Rng>(rng: &mut R, depth: u32) -> Value {
+    const COLORS: [&str; 6] = ["red", "orange", "yellow", "green", "blue", "violet"];
+
+    match rng.gen_range(0..if depth < 5 { 5 } else { 3 }) {
+        0 | 1 => Value::from(rng.gen_range(-50..200)),
+        2 => Value::from(*COLORS.choose(rng).unwrap()),
+        3 => Value::Array(
+            (0..rng.gen_range(1..6))
+                .map(|_| json_value(rng, depth + 1))
+                .collect(),
+        ),
+        _ => {
+            let mut object = Map::new();
+            for _ in 0..rng.gen_range(1..6) {
+                object.insert(word(rng, 1), json_value(rng, depth + 1));
+            }
+
+            Value::Object(object)
+        }
+    }
+}
+
+/// A document about as large as the real ones (25 kB)
+pub fn day12<R: Rng>(rng: &mut R) -> String {
+    let mut document = vec![];
+    let mut len = 0;
+    while len < 25_000 {
+        let value = json_value(rng, 1);
+        len += value.to_string().len();
+        document.push(value);
+    }
+
+    Value::Array(document).to_string()
+}
+
+/// Every guest feels something about every other one
+pub fn day13<R: Rng>(rng: &mut R, guests: usize) -> String {
+    let guests = names(rng, guests);
+    let mut input = String::new();
+    for guest in &guests {
+        for neighbor in guests.iter().filter(|&neighbor| neighbor != guest) {
+            let units: i32 = rng.gen_range(-100..=100);
+            let change = if units < 0 { "lose" } else { "gain" };
+            writeln!(
+                input,
+                "{} would {} {} happiness units by sitting next to {}.",
+                guest,
+                change,
+                units.abs(),
+                neighbor
+            )
+            .unwrap();
+        }
+    }
+
+    lines(input)
+}
+
+pub fn day14<R: Rng>(rng: &mut R, reindeer: usize) -> String {
+    let mut input = String::new();
+    for reindeer in names(rng, reindeer) {
+        writeln!(
+            input,
+            "{} can fly {} km/s for {} seconds, but then must rest for {} seconds.",
+            reindeer,
+            rng.gen_range(2..30),
+            rng.gen_range(2..20),
+            rng.gen_range(40..180)
+        )
+        .unwrap();
+    }
+
+    lines(input)
+}
+
+/// Each ingredient is good at one property, and one of them weighs exactly 5 calories
+/// so some recipe has 500 calories
+pub fn day15<R: Rng>(rng: &mut R) -> String {
+    const INGREDIENTS: [&str; 4] = ["Sprinkles", "PeanutButter", "Frosting", "Sugar"];
+
+    let mut input = String::new();
+    for (i, ingredient) in INGREDIENTS.into_iter().enumerate() {
+        let [capacity, durability, flavor, texture] = [0, 1, 2, 3].map(|property| {
+            if property == i {
+                rng.gen_range(2..=5)
+            } else {
+                rng.gen_range(-3..=1)
+            }
+        });
+        let calories = if i == 0 { 5 } else { rng.gen_range(1..=9) };
+        writeln!(
+            input,
+            "{}: capacity {}, durability {}, flavor {}, texture {}, calories {}",
+            ingredient, capacity, durability, flavor, texture, calories
+        )
+        .unwrap();
+    }
+
+    lines(input)
+}
+
+/// `sues` Sues (at least 2): one matching each part, every other one remembering a wrong exact amount
+pub fn day16<R: Rng>(rng: &mut R, sues: usize) -> String {
+    // compared exactly in both parts
+    const EXACT: [usize; 6] = [0, 2, 4, 5, 8, 9];
+
+    let mut sues: Vec<[(usize, u32); 3]> = (2..sues)
+        .map(|_| {
+            let wrong = *EXACT.choose(rng).unwrap();
+            let others: Vec<_> = (0..MFCSAM_READOUT.len())
+                .filter(|&compound| compound != wrong)
+                .collect();
+            let others: Vec<_> = others.choose_multiple(rng, 2).copied().collect();
+            let wrong_amounts: Vec<_> = (0..=10)
+                .filter(|&amount| amount != MFCSAM_READOUT[wrong].1)
+                .collect();
+            [
+                (wrong, *wrong_amounts.choose(rng).unwrap()),
+                (others[0], rng.gen_range(0..=10)),
+                (others[1], rng.gen_range(0..=10)),
+            ]
+        })
+        .collect();
+
+    // cats: 7 is exact in part 1 and too few in part 2
+    sues.push([(1, 7), (8, 2), (0, 3)]);
+    sues.push([(1, 9), (6, 1), (0, 3)]);
+    sues.shuffle(rng);
+
+    let mut input = String::new();
+    for (number, sue) in sues.iter().enumerate() {
+        let compounds = sue
+            .iter()
+            .map(|&(compound, amount)| format!("{}: {}", MFCSAM_READOUT[compound].0, amount))
+            .collect::<Vec<_>>()
+            .join(", ");
+        writeln!(input, "Sue {}: {}", number + 1, compounds).unwrap();
+    }
+
+    lines(input)
+}
+
+pub fn day17<R: Rng>(rng: &mut R, containers: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..containers {
+        writeln!(input, "{}", rng.gen_range(3..=50)).unwrap();
+    }
+
+    lines(input)
+}
+
+/// A square grid of `side` by `side` lights
+pub fn day18<R: Rng>(rng: &mut R, side: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..side {
+        let row: String = (0..side)
+            .map(|_| if rng.gen_bool(0.5) { '#' } else { '.' })
+            .collect();
+        writeln!(input, "{}", row).unwrap();
+    }
+
+    lines(input)
+}
+
+/// The replacements every real input uses
+const DAY19_RULES: [(&str, &str); 43] = [
+    ("Al", "ThF"),
+    ("Al", "ThRnFAr"),
+    ("B", "BCa"),
+    ("B", "TiB"),
+    ("B", "TiRnFAr"),
+    ("Ca", "CaCa"),
+    ("Ca", "PB"),
+    ("Ca", "PRnFAr"),
+    ("Ca", "SiRnFYFAr"),
+    ("Ca", "SiRnMgAr"),
+    ("Ca", "SiTh"),
+    ("F", "CaF"),
+    ("F", "PMg"),
+    ("F", "SiAl"),
+    ("H", "CRnAlAr"),
+    ("H", "CRnFYFYFAr"),
+    ("H", "CRnFYMgAr"),
+    ("H", "CRnMgYFAr"),
+    ("H", "HCa"),
+    ("H", "NRnFYFAr"),
+    ("H", "NRnMgAr"),
+    ("H", "NTh"),
+    ("H", "OB"),
+    ("H", "ORnFAr"),
+    ("Mg", "BF"),
+    ("Mg", "TiMg"),
+    ("N", "CRnFAr"),
+    ("N", "HSi"),
+    ("O", "CRnFYFAr"),
+    ("O", "CRnMgAr"),
+    ("O", "HP"),
+    ("O", "NRnFAr"),
+    ("O", "OTi"),
+    ("P", "CaP"),
+    ("P", "PTi"),
+    ("P", "SiRnFAr"),
+    ("Si", "CaSi"),
+    ("Th", "ThCa"),
+    ("Ti", "BP"),
+    ("Ti", "TiTi"),
+    ("e", "HF"),
+    ("e", "NAl"),
+    ("e", "OMg"),
+];
+
+/// `e` and the symbols starting with an uppercase letter
+fn elements(molecule: &str) -> Vec<&str> {
+    let starts: Vec<_> = molecule
+        .match_indices(|c: char| c.is_ascii_uppercase() || c == 'e')
+        .map(|(start, _)| start)
+        .chain([molecule.len()])
+        .collect();
+
+    starts
+        .windows(2)
+        .map(|bounds| &molecule[bounds[0]..bounds[1]])
+        .collect()
+}
+
+/// A medicine of at least `len` elements, grown from `e` with random replacements
+pub fn day19<R: Rng>(rng: &mut R, len: usize) -> String {
+    let start = DAY19_RULES[40..].choose(rng).unwrap().1;
+    let mut molecule = elements(start);
+    while molecule.len() < len {
+        let at = rng.gen_range(0..molecule.len());
+        let replacements: Vec<_> = DAY19_RULES
+            .iter()
+            .filter(|&&(from, _)| from == molecule[at])
+            .collect();
+
+        if let Some(&&(_, to)) = replacements.choose(rng) {
+            molecule.splice(at..=at, elements(to));
+        }
+    }
+
+    let mut input = String::new();
+    for (from, to) in DAY19_RULES {
+        writeln!(input, "{} => {}", from, to).unwrap();
+    }
+
+    input + "\n" + &molecule.concat()
+}
+
+pub fn day20<R: Rng>(rng: &mut R) -> String {
+    rng.gen_range(29_000_000..36_000_000).to_string()
+}
+
+pub fn day21<R: Rng>(rng: &mut R) -> String {
+    format!(
+        "Hit Points: {}\nDamage: {}\nArmor: {}",
+        rng.gen_range(100..=109),
+        rng.gen_range(7..=9),
+        rng.gen_range(1..=3)
+    )
+}
+
+pub fn day22<R: Rng>(rng: &mut R) -> String {
+    format!(
+        "Hit Points: {}\nDamage: {}",
+        rng.gen_range(51..=71),
+        rng.gen_range(8..=10)
+    )
+}
+
+/// `a` starts at one value or another depending on the part, then the program
+/// counts the steps of its Collatz sequence in `b`
+pub fn day23<R: Rng>(rng: &mut R) -> String {
+    fn build<R: Rng>(rng: &mut R, lengths: Range<usize>) -> Vec<&'static str> {
+        let len = rng.gen_range(lengths);
+        let mut block = vec!["inc a"];
+        block.extend((1..len).map(|_| if rng.gen_bool(0.6) { "tpl a" } else { "inc a" }));
+        block
+    }
+
+    let part1 = build(rng, 15..25);
+    let part2 = build(rng, 25..35);
+
+    let mut program = vec![format!("jio a, +{}", part1.len() + 2)];
+    program.extend(part1.iter().map(|&line| line.to_owned()));
+    program.push(format!("jmp +{}", part2.len() + 1));
+    program.extend(part2.iter().map(|&line| line.to_owned()));
+    program.extend(
+        [
+            "jio a, +8",
+            "inc b",
+            "jie a, +4",
+            "tpl a",
+            "inc a",
+            "jmp +2",
+            "hlf a",
+            "jmp -7",
+        ]
+        .map(str::to_owned),
+    );
+
+    program.join("\n")
+}
+
+/// 1 and primes up to 113 with a few left out, always a multiple of 12 in total
+pub fn day24<R: Rng>(rng: &mut R) -> String {
+    const WEIGHTS: [u64; 30] = [
+        1, 2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83,
+        89, 97, 101, 103, 107, 113,
+    ];
+
+    loop {
+        let weights: Vec<u64> = WEIGHTS
+            .iter()
+            .copied()
+            .filter(|_| rng.gen_bool(0.95))
+            .collect();
+
+        if weights.iter().sum::<u64>() % 12 == 0 {
+            return weights
+                .iter()
+                .map(u64::to_string)
+                .collect::<Vec<_>>()
+                .join("\n");
+        }
+    }
+}
+
+pub fn day25<R: Rng>(rng: &mut R) -> String {
+    format!(
+        "To continue, please consult the code grid in the manual.  Enter the code at row {}, column {}.",
+        rng.gen_range(2000..3100),
+        rng.gen_range(2000..3100)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution;
+
+    #[test]
+    fn every_day_parses() {
+        for day in 1..=25 {
+            let input = input(day, 2015).unwrap();
+            for solution in solution::all().filter(|solution| solution.day() == day) {
+                if let Err(err) = solution.parse(&input) {
+                    panic!("day{}: {}\n{}", day, err, input);
+                }
+            }
+        }
+
+        assert!(input(26, 2015).is_none());
+    }
+
+    #[test]
+    fn reproducible() {
+        assert_eq!(input(7, 1), input(7, 1));
+        assert_ne!(input(7, 1), input(7, 2));
+    }
+
+    #[test]
+    fn scales() {
+        let rng = &mut seeded(0);
+        assert_eq!(day02(rng, 50_000).lines().count(), 50_000);
+        assert_eq!(day09(rng, 20).lines().count(), 20 * 19 / 2);
+        assert!(crate::day07::parse(&day07(rng, 5000)).is_ok());
+    }
+}
@@ -0,0 +1,169 @@
+use std::{fmt, io, ops::Add};
+
+use fnv::{FnvHashMap, FnvHashSet};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Objective {
+    Minimize,
+    Maximize,
+}
+
+impl Objective {
+    #[inline]
+    fn pick<W: Ord>(self, a: W, b: W) -> W {
+        match self {
+            Self::Minimize => a.min(b),
+            Self::Maximize => a.max(b),
+        }
+    }
+}
+
+/// Undirected weighted graph, vertices are identified by name
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Graph<W = u64> {
+    vertices: Vec<(String, FnvHashMap<usize, W>)>,
+    vertex_name_to_id: FnvHashMap<String, usize>,
+}
+
+impl<W> Default for Graph<W> {
+    fn default() -> Self {
+        Self {
+            vertices: vec![],
+            vertex_name_to_id: FnvHashMap::default(),
+        }
+    }
+}
+
+impl<W> Graph<W>
+where
+    W: Copy + Ord + Default + Add<Output = W>,
+{
+    pub fn len(&self) -> usize {
+        self.vertices.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.vertices.is_empty()
+    }
+
+    pub fn vertex_names(&self) -> impl Iterator<Item = &str> {
+        self.vertices.iter().map(|(name, _)| name.as_str())
+    }
+
+    pub fn get_or_insert_vertex(&mut self, name: &str) -> usize {
+        if let Some(&id) = self.vertex_name_to_id.get(name) {
+            id
+        } else {
+            let new_id = self.vertices.len();
+            self.vertices.push((name.to_owned(), FnvHashMap::default()));
+            self.vertex_name_to_id.insert(name.to_owned(), new_id);
+
+            new_id
+        }
+    }
+
+    /// Adds `weight` to the edge between `a` and `b`, creating the vertices and the edge if needed
+    pub fn add_to_edge(&mut self, a: &str, b: &str, weight: W) {
+        let a_id = self.get_or_insert_vertex(a);
+        let b_id = self.get_or_insert_vertex(b);
+
+        let entry = self.vertices[a_id].1.entry(b_id).or_default();
+        *entry = *entry + weight;
+        let entry = self.vertices[b_id].1.entry(a_id).or_default();
+        *entry = *entry + weight;
+    }
+
+    /// Sets the edge between `a` and `b` to `weight`, replacing any previous weight,
+    /// creating the vertices and the edge if needed
+    pub fn set_edge(&mut self, a: &str, b: &str, weight: W) {
+        let a_id = self.get_or_insert_vertex(a);
+        let b_id = self.get_or_insert_vertex(b);
+
+        self.vertices[a_id].1.insert(b_id, weight);
+        self.vertices[b_id].1.insert(a_id, weight);
+    }
+
+    #[inline]
+    pub fn weight(&self, a: usize, b: usize) -> Option<W> {
+        self.vertices[a].1.get(&b).copied()
+    }
+
+    /// Returns `None` if there is no hamiltonian path
+    pub fn best_hamiltonian_path(&self, objective: Objective) -> Option<W> {
+        let mut visited = FnvHashSet::default();
+        (0..self.len())
+            .filter_map(|i| self.hamiltonian_impl(i, None, &mut visited, objective))
+            .reduce(|a, b| objective.pick(a, b))
+    }
+
+    /// Returns `None` if there is no hamiltonian cycle
+    pub fn best_hamiltonian_cycle(&self, objective: Objective) -> Option<W> {
+        if self.vertices.is_empty() {
+            return None;
+        }
+
+        // every cycle goes through vertex 0, no need to try every start
+        self.hamiltonian_impl(0, Some(0), &mut FnvHashSet::default(), objective)
+    }
+
+    fn hamiltonian_impl(
+        &self,
+        current: usize,
+        cycle_start: Option<usize>,
+        visited: &mut FnvHashSet<usize>,
+        objective: Objective,
+    ) -> Option<W> {
+        visited.insert(current);
+
+        let result = if visited.len() == self.len() {
+            match cycle_start {
+                Some(start) if start == current => Some(W::default()),
+                Some(start) => self.weight(current, start),
+                None => Some(W::default()),
+            }
+        } else {
+            let mut best = None;
+            for (&target, &weight) in self.vertices[current].1.iter() {
+                if visited.contains(&target) {
+                    continue;
+                }
+
+                if let Some(rest) = self.hamiltonian_impl(target, cycle_start, visited, objective) {
+                    let total = weight + rest;
+                    best = Some(best.map_or(total, |best| objective.pick(best, total)));
+                }
+            }
+
+            best
+        };
+
+        visited.remove(&current);
+        result
+    }
+}
+
+impl<W: fmt::Display> Graph<W> {
+    pub fn write_as_gv<Wr: io::Write>(&self, writer: &mut Wr) -> io::Result<()> {
+        writeln!(writer, "graph {{\n    layout = \"circo\"\n")?;
+        for (vertex_id, (vertex, _)) in self.vertices.iter().enumerate() {
+            writeln!(writer, "    v{} [label={:?}]", vertex_id, vertex)?;
+        }
+
+        writeln!(writer)?;
+        for (vertex_id, (_, adjacents)) in self.vertices.iter().enumerate() {
+            for (&target_vertex, weight) in adjacents.iter() {
+                if vertex_id < target_vertex {
+                    writeln!(
+                        writer,
+                        r#"    v{} -- v{} [label="{}"]"#,
+                        vertex_id, target_vertex, weight
+                    )?;
+                }
+            }
+        }
+
+        writeln!(writer, "}}")?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,75 @@
+use std::ops::{Index, IndexMut};
+
+/// Rectangular grid of lights, indexed by `(row, col)`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T = bool> {
+    cells: Box<[Box<[T]>]>,
+    cols: usize,
+}
+
+impl<T: Default + Clone> Grid<T> {
+    pub fn new(rows: usize, cols: usize) -> Self {
+        Self {
+            cells: (0..rows)
+                .map(|_| vec![T::default(); cols].into_boxed_slice())
+                .collect(),
+            cols,
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Returns `None` if the rows are not all the same length
+    pub fn from_rows<R: IntoIterator<Item = Box<[T]>>>(rows: R) -> Option<Self> {
+        let cells: Box<[_]> = rows.into_iter().collect();
+        let cols = cells.first().map_or(0, |row| row.len());
+        if cells.iter().any(|row| row.len() != cols) {
+            return None;
+        }
+
+        Some(Self { cells, cols })
+    }
+
+    #[inline]
+    pub fn rows(&self) -> usize {
+        self.cells.len()
+    }
+
+    #[inline]
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter().flat_map(|row| row.iter())
+    }
+
+    /// The (up to 8) positions surrounding `(row, col)`
+    #[inline]
+    pub fn neighbours(&self, row: usize, col: usize) -> impl Iterator<Item = (usize, usize)> {
+        let rows = self.rows();
+        let cols = self.cols();
+        (row.saturating_sub(1)..=(row + 1).min(rows - 1))
+            .flat_map(move |r| {
+                (col.saturating_sub(1)..=(col + 1).min(cols - 1)).map(move |c| (r, c))
+            })
+            .filter(move |&pos| pos != (row, col))
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+        &self.cells[row][col]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    #[inline]
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut Self::Output {
+        &mut self.cells[row][col]
+    }
+}
@@ -0,0 +1,100 @@
+use std::{
+    borrow::Cow,
+    env, fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::answers::ANSWERS_FILE;
+
+/// Overrides the directory the puzzle inputs are read from
+pub const INPUT_DIR_VAR: &str = "AOC2015_INPUT_DIR";
+
+/// Where the inputs live when `INPUT_DIR_VAR` is not set, relative to the working directory
+pub const DEFAULT_INPUT_DIR: &str = "inputs/2015";
+
+/// Uses `\n` line endings and removes every trailing newline,
+/// so every generator sees the same text whatever the input file was saved with
+pub fn normalize(input: &str) -> Cow<'_, str> {
+    let input = input.trim_end_matches(['\n', '\r']);
+    if input.contains('\r') {
+        Cow::Owned(input.replace("\r\n", "\n"))
+    } else {
+        Cow::Borrowed(input)
+    }
+}
+
+/// Finds the puzzle inputs stored locally as `dayNN.txt`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct InputProvider {
+    dir: PathBuf,
+}
+
+impl InputProvider {
+    pub fn new<P: Into<PathBuf>>(dir: P) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// The directory named by `INPUT_DIR_VAR`, or `DEFAULT_INPUT_DIR`
+    pub fn from_env() -> Self {
+        Self::new(
+            env::var_os(INPUT_DIR_VAR).map_or_else(|| DEFAULT_INPUT_DIR.into(), PathBuf::from),
+        )
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub fn path(&self, day: u32) -> PathBuf {
+        self.dir.join(format!("day{:02}.txt", day))
+    }
+
+    /// Where the known answers of these inputs are recorded
+    pub fn answers_path(&self) -> PathBuf {
+        self.dir.join(ANSWERS_FILE)
+    }
+
+    /// The normalized input of `day`
+    pub fn load(&self, day: u32) -> io::Result<String> {
+        let path = self.path(day);
+        let input = fs::read_to_string(&path)
+            .map_err(|err| io::Error::new(err.kind(), format!("{}: {}", path.display(), err)))?;
+
+        Ok(normalize(&input).into_owned())
+    }
+}
+
+impl Default for InputProvider {
+    fn default() -> Self {
+        Self::from_env()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalized_newlines() {
+        assert_eq!(normalize("1x2x3\n4x5x6\n\n"), "1x2x3\n4x5x6");
+        assert_eq!(normalize("1x2x3\r\n4x5x6\r\n"), "1x2x3\n4x5x6");
+        assert_eq!(normalize("  ((\n"), "  ((");
+    }
+
+    #[test]
+    fn load_from_dir() {
+        let dir = env::temp_dir().join(format!("aoc2015-inputs-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("day07.txt"), "123 -> a\r\n\r\n").unwrap();
+
+        let provider = InputProvider::new(&dir);
+        assert_eq!(provider.path(7), dir.join("day07.txt"));
+        assert_eq!(provider.load(7).unwrap(), "123 -> a");
+        assert_eq!(
+            provider.load(8).unwrap_err().kind(),
+            io::ErrorKind::NotFound
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -15,5 +15,33 @@ pub mod day09;
 pub mod day10;
 pub mod day11;
 pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+
+pub mod answers;
+pub mod error;
+pub mod generators;
+pub mod input;
+// timing relies on `Instant::now`, which panics on wasm32-unknown-unknown
+#[cfg(not(target_arch = "wasm32"))]
+pub mod runner;
+pub mod solution;
+
+pub mod graph;
+pub mod grid;
+
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
 aoc_lib! { year = 2015}
@@ -0,0 +1,297 @@
+use std::{
+    any::Any,
+    fmt,
+    panic::{catch_unwind, AssertUnwindSafe},
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use rayon::prelude::*;
+use serde_json::{json, Value};
+
+use crate::solution::{Answer, Solution};
+
+/// The answer of one solution and how long it took
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Run {
+    pub day: u32,
+    pub part: u32,
+    pub name: Option<&'static str>,
+    /// The answer, or why there is none
+    pub answer: Result<Answer, String>,
+    /// Time spent in the generator
+    pub parse_time: Duration,
+    /// Time spent in the solver, zero when the input was invalid
+    pub solve_time: Duration,
+}
+
+/// What a solver panicked with, so one bad solver does not take the whole report down
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    let message = match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => payload
+            .downcast_ref::<&str>()
+            .map_or("no message", |message| message)
+            .to_owned(),
+    };
+
+    format!("panicked: {}", message)
+}
+
+impl Run {
+    pub fn new(solution: &dyn Solution, input: &str) -> Self {
+        let start = Instant::now();
+        let parsed = catch_unwind(AssertUnwindSafe(|| solution.parse(input)));
+        let generated = Instant::now();
+
+        let (answer, solve_time) = match parsed {
+            Ok(Ok(parsed)) => {
+                let answer = match catch_unwind(AssertUnwindSafe(|| parsed.solve())) {
+                    Ok(answer) => answer.map_err(|err| err.to_string()),
+                    Err(payload) => Err(panic_message(payload)),
+                };
+                (answer, generated.elapsed())
+            }
+            Ok(Err(err)) => (Err(format!("invalid input: {}", err)), Duration::ZERO),
+            Err(payload) => (Err(panic_message(payload)), Duration::ZERO),
+        };
+
+        Self {
+            day: solution.day(),
+            part: solution.part(),
+            name: solution.name(),
+            answer,
+            parse_time: generated - start,
+            solve_time,
+        }
+    }
+
+    /// Name of the alternative, `default` for the default solver
+    pub fn variant(&self) -> &'static str {
+        self.name.unwrap_or("default")
+    }
+}
+
+/// Runs every solution on its input, in order, on the rayon pool when `parallel` is set
+pub fn run_all(jobs: &[(&dyn Solution, &str)], parallel: bool) -> Vec<Run> {
+    if parallel {
+        jobs.par_iter()
+            .map(|&(solution, input)| Run::new(solution, input))
+            .collect()
+    } else {
+        jobs.iter()
+            .map(|&(solution, input)| Run::new(solution, input))
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ReportFormat {
+    #[default]
+    Table,
+    Json,
+    Markdown,
+}
+
+impl FromStr for ReportFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "table" => Ok(Self::Table),
+            "json" => Ok(Self::Json),
+            "markdown" | "md" => Ok(Self::Markdown),
+            _ => Err(format!(
+                "Unknown format {:?}, expected table, json or markdown",
+                s
+            )),
+        }
+    }
+}
+
+/// The runs of a run-all, printed as a table, JSON or Markdown
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Report<'a> {
+    pub runs: &'a [Run],
+    pub format: ReportFormat,
+}
+
+const HEADERS: [&str; 6] = ["day", "part", "variant", "answer", "parse", "solve"];
+
+/// Multi-line answers are kept on one line, rows of a grid are separated by `/`
+fn cells(run: &Run) -> [String; 6] {
+    [
+        run.day.to_string(),
+        run.part.to_string(),
+        run.variant().to_owned(),
+        match &run.answer {
+            Ok(answer) => answer.to_string().replace('\n', "/"),
+            Err(err) => format!("error: {}", err),
+        },
+        format!("{:?}", run.parse_time),
+        format!("{:?}", run.solve_time),
+    ]
+}
+
+impl Report<'_> {
+    fn write_table(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rows: Vec<_> = self.runs.iter().map(cells).collect();
+        let widths = HEADERS.map(str::len);
+        let widths = rows.iter().fold(widths, |mut widths, row| {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+
+            widths
+        });
+
+        let header = HEADERS.map(str::to_owned);
+        for row in [&header].into_iter().chain(&rows) {
+            let line: Vec<_> = row
+                .iter()
+                .zip(widths)
+                .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+                .collect();
+            writeln!(f, "{}", line.join("  ").trim_end())?;
+        }
+
+        Ok(())
+    }
+
+    fn write_markdown(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "| {} |", HEADERS.join(" | "))?;
+        writeln!(f, "|{}", "---|".repeat(HEADERS.len()))?;
+        for run in self.runs {
+            let row = cells(run).map(|cell| cell.replace('|', "\\|"));
+            writeln!(f, "| {} |", row.join(" | "))?;
+        }
+
+        Ok(())
+    }
+
+    fn write_json(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let answer = |answer: &Answer| match answer {
+            Answer::Integer(answer) => json!(answer),
+            Answer::Text(answer) => json!(answer),
+            Answer::Grid(rows) => json!(rows),
+        };
+
+        let runs: Vec<_> = self
+            .runs
+            .iter()
+            .map(|run| {
+                json!({
+                    "day": run.day,
+                    "part": run.part,
+                    "variant": run.variant(),
+                    "answer": run.answer.as_ref().ok().map(answer),
+                    "error": run.answer.as_ref().err(),
+                    "parse_ns": run.parse_time.as_nanos() as u64,
+                    "solve_ns": run.solve_time.as_nanos() as u64,
+                })
+            })
+            .collect();
+
+        writeln!(f, "{:#}", Value::Array(runs))
+    }
+}
+
+impl fmt::Display for Report<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.format {
+            ReportFormat::Table => self.write_table(f),
+            ReportFormat::Json => self.write_json(f),
+            ReportFormat::Markdown => self.write_markdown(f),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution;
+
+    fn jobs() -> Vec<(&'static dyn Solution, &'static str)> {
+        solution::all()
+            .filter(|solution| solution.day() == 1)
+            .map(|solution| (solution, "(()))"))
+            .chain([(solution::find(2, 1, None).unwrap(), "2x3")])
+            .collect()
+    }
+
+    #[test]
+    fn run_in_order() {
+        let answers = |runs: Vec<Run>| -> Vec<_> {
+            runs.into_iter()
+                .map(|run| (run.day, run.part, run.answer))
+                .collect()
+        };
+
+        let runs = run_all(&jobs(), true);
+        assert_eq!(runs[0].answer, Ok(Answer::Integer(-1)));
+        assert_eq!(runs[1].answer, Ok(Answer::Integer(5)));
+        assert!(runs[2]
+            .answer
+            .as_ref()
+            .unwrap_err()
+            .starts_with("invalid input: "));
+        assert_eq!(runs[2].solve_time, Duration::ZERO);
+        assert_eq!(answers(runs), answers(run_all(&jobs(), false)));
+    }
+
+    #[test]
+    fn survive_panics() {
+        // never reaches the basement, valid for part 1 only
+        let jobs: Vec<_> = solution::all()
+            .filter(|solution| solution.day() == 1)
+            .map(|solution| (solution, "((("))
+            .collect();
+
+        for parallel in [false, true] {
+            let runs = run_all(&jobs, parallel);
+            assert_eq!(runs[0].answer, Ok(Answer::Integer(3)));
+            assert_eq!(
+                runs[1].answer,
+                Err("panicked: internal error: entered unreachable code".to_owned())
+            );
+        }
+    }
+
+    #[test]
+    fn reports() {
+        let runs = run_all(&jobs(), false);
+        let markdown = Report {
+            runs: &runs,
+            format: ReportFormat::Markdown,
+        }
+        .to_string();
+        assert!(markdown.starts_with("| day | part | variant | answer | parse | solve |\n|---|"));
+        assert!(markdown.contains("\n| 1 | 2 | default | 5 | "));
+
+        let table = Report {
+            runs: &runs,
+            format: ReportFormat::Table,
+        }
+        .to_string();
+        assert_eq!(table.lines().count(), 4);
+        assert!(table
+            .lines()
+            .nth(2)
+            .unwrap()
+            .starts_with("1    2     default  5 "));
+
+        let json: Value = Report {
+            runs: &runs,
+            format: ReportFormat::Json,
+        }
+        .to_string()
+        .parse()
+        .unwrap();
+        assert_eq!(json[0]["answer"], -1);
+        assert_eq!(json[2]["answer"], Value::Null);
+        assert_eq!(json[2]["variant"], "default");
+
+        assert_eq!("md".parse(), Ok(ReportFormat::Markdown));
+        assert!("csv".parse::<ReportFormat>().is_err());
+    }
+}
@@ -0,0 +1,300 @@
+use std::{convert::Infallible, error::Error, fmt, str::FromStr};
+
+use crate::error::ParseError;
+
+/// The answer of a part, whatever type its solver returns
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Integer(i64),
+    Text(String),
+    /// Rows of a picture, for the puzzles whose answer is drawn with pixels
+    Grid(Vec<String>),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Integer(answer) => write!(f, "{}", answer),
+            Self::Text(answer) => write!(f, "{}", answer),
+            Self::Grid(rows) => write!(f, "{}", rows.join("\n")),
+        }
+    }
+}
+
+/// The reverse of `Display`, for answers stored as text
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(if s.contains('\n') {
+            Self::Grid(s.lines().map(str::to_owned).collect())
+        } else if let Ok(answer) = s.parse() {
+            Self::Integer(answer)
+        } else {
+            Self::Text(s.to_owned())
+        })
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(answer: i64) -> Self {
+        Self::Integer(answer)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(answer: String) -> Self {
+        Self::Text(answer)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(answer: &str) -> Self {
+        Self::Text(answer.to_owned())
+    }
+}
+
+/// One way of solving a part of a day, reachable without `cargo aoc`
+pub trait Solution: Sync {
+    fn day(&self) -> u32;
+
+    fn part(&self) -> u32;
+
+    /// Name of the alternative (e.g. `whole_grid`), `None` for the default solver
+    fn name(&self) -> Option<&'static str>;
+
+    /// Runs the day's generator on `input`
+    fn parse(&self, input: &str) -> Result<Box<dyn Parsed>, ParseError>;
+
+    fn solve(&self, input: &str) -> Result<Answer, Box<dyn Error>> {
+        self.parse(input)?.solve()
+    }
+}
+
+/// The output of a generator, ready to be solved
+pub trait Parsed {
+    fn solve(&self) -> Result<Answer, Box<dyn Error>>;
+}
+
+/// Every return type of the solvers, turned into an `Answer`
+pub(crate) trait IntoAnswer {
+    fn into_answer(self) -> Result<Answer, Box<dyn Error>>;
+}
+
+macro_rules! into_integer_answer {
+    ($($ty:ty),*) => {
+        $(
+            impl IntoAnswer for $ty {
+                #[inline]
+                fn into_answer(self) -> Result<Answer, Box<dyn Error>> {
+                    let answer = i64::try_from(self)
+                        .map_err(|_| format!("The answer {} does not fit in an i64", self))?;
+                    Ok(Answer::Integer(answer))
+                }
+            }
+        )*
+    };
+}
+
+into_integer_answer!(u64, usize);
+
+macro_rules! into_integer_answer_losslessly {
+    ($($ty:ty),*) => {
+        $(
+            impl IntoAnswer for $ty {
+                #[inline]
+                fn into_answer(self) -> Result<Answer, Box<dyn Error>> {
+                    Ok(Answer::Integer(self.into()))
+                }
+            }
+        )*
+    };
+}
+
+into_integer_answer_losslessly!(u16, u32, i64);
+
+impl IntoAnswer for String {
+    fn into_answer(self) -> Result<Answer, Box<dyn Error>> {
+        Ok(Answer::Text(self))
+    }
+}
+
+impl<T: IntoAnswer> IntoAnswer for Option<T> {
+    fn into_answer(self) -> Result<Answer, Box<dyn Error>> {
+        self.ok_or("The solver did not find any answer")?
+            .into_answer()
+    }
+}
+
+impl<T: IntoAnswer, E: Into<Box<dyn Error>>> IntoAnswer for Result<T, E> {
+    fn into_answer(self) -> Result<Answer, Box<dyn Error>> {
+        self.map_err(Into::into)?.into_answer()
+    }
+}
+
+/// A generator and a solver, `T` is what the generator returns
+pub(crate) struct Solver<T, A> {
+    day: u32,
+    part: u32,
+    name: Option<&'static str>,
+    parse: fn(&str) -> Result<T, ParseError>,
+    solve: fn(&T) -> A,
+}
+
+impl<T, A> Solver<T, A> {
+    pub(crate) const fn new(
+        day: u32,
+        part: u32,
+        parse: fn(&str) -> Result<T, ParseError>,
+        solve: fn(&T) -> A,
+    ) -> Self {
+        Self {
+            day,
+            part,
+            name: None,
+            parse,
+            solve,
+        }
+    }
+
+    pub(crate) const fn named(mut self, name: &'static str) -> Self {
+        self.name = Some(name);
+        self
+    }
+}
+
+/// For the days solved straight from the puzzle input
+pub(crate) fn raw_input(input: &str) -> Result<String, ParseError> {
+    Ok(input.to_owned())
+}
+
+struct Generated<T, A> {
+    input: T,
+    solve: fn(&T) -> A,
+}
+
+impl<T, A: IntoAnswer> Parsed for Generated<T, A> {
+    fn solve(&self) -> Result<Answer, Box<dyn Error>> {
+        (self.solve)(&self.input).into_answer()
+    }
+}
+
+impl<T: 'static, A: IntoAnswer + 'static> Solution for Solver<T, A> {
+    fn day(&self) -> u32 {
+        self.day
+    }
+
+    fn part(&self) -> u32 {
+        self.part
+    }
+
+    fn name(&self) -> Option<&'static str> {
+        self.name
+    }
+
+    fn parse(&self, input: &str) -> Result<Box<dyn Parsed>, ParseError> {
+        Ok(Box::new(Generated {
+            input: (self.parse)(input)?,
+            solve: self.solve,
+        }))
+    }
+}
+
+/// Every registered solution, ordered by day then part
+pub fn all() -> impl Iterator<Item = &'static dyn Solution> {
+    use crate::*;
+
+    [
+        day01::SOLUTIONS,
+        day02::SOLUTIONS,
+        day03::SOLUTIONS,
+        day04::SOLUTIONS,
+        day05::SOLUTIONS,
+        day06::SOLUTIONS,
+        day07::SOLUTIONS,
+        day08::SOLUTIONS,
+        day09::SOLUTIONS,
+        day10::SOLUTIONS,
+        day11::SOLUTIONS,
+        day12::SOLUTIONS,
+        day13::SOLUTIONS,
+        day14::SOLUTIONS,
+        day15::SOLUTIONS,
+        day16::SOLUTIONS,
+        day17::SOLUTIONS,
+        day18::SOLUTIONS,
+        day19::SOLUTIONS,
+        day20::SOLUTIONS,
+        day21::SOLUTIONS,
+        day22::SOLUTIONS,
+        day23::SOLUTIONS,
+        day24::SOLUTIONS,
+        day25::SOLUTIONS,
+    ]
+    .into_iter()
+    .flatten()
+    .copied()
+}
+
+/// The solution called `name`, or the default one of that part when `name` is `None`.
+///
+/// Parts only solved by named alternatives default to the first one registered.
+pub fn find(day: u32, part: u32, name: Option<&str>) -> Option<&'static dyn Solution> {
+    let mut candidates = all().filter(|solution| solution.day() == day && solution.part() == part);
+    match name {
+        Some(name) => candidates.find(|solution| solution.name() == Some(name)),
+        None => {
+            let candidates: Vec<_> = candidates.collect();
+            candidates
+                .iter()
+                .find(|solution| solution.name().is_none())
+                .or_else(|| candidates.first())
+                .copied()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn registry() {
+        assert_eq!(
+            all().filter(|solution| solution.name().is_none()).count(),
+            44
+        );
+        assert_eq!(find(6, 1, None).unwrap().name(), Some("whole_grid"));
+        assert!(find(20, 1, Some("rayon")).is_some());
+        assert!(find(25, 2, None).is_none());
+        assert_eq!(
+            find(1, 2, None).unwrap().solve("()())").unwrap(),
+            Answer::Integer(5)
+        );
+        assert_eq!(
+            find(11, 1, None).unwrap().solve("abcdefgh").unwrap(),
+            Answer::Text("abcdffaa".to_owned())
+        );
+        assert!(find(2, 1, None).unwrap().solve("2x3").is_err());
+    }
+
+    #[test]
+    fn answers() {
+        assert_eq!(Answer::Integer(-1).to_string(), "-1");
+        assert_eq!(
+            Answer::Grid(vec!["#..".to_owned(), ".#.".to_owned()]).to_string(),
+            "#..\n.#."
+        );
+
+        for text in ["-1", "hepxxyzz", "#..\n.#."] {
+            assert_eq!(text.parse::<Answer>().unwrap().to_string(), text);
+        }
+        assert_eq!("1771".parse(), Ok(Answer::Integer(1771)));
+
+        assert_eq!(
+            u64::MAX.into_answer().unwrap_err().to_string(),
+            "The answer 18446744073709551615 does not fit in an i64"
+        );
+        assert_eq!(Some(7u16).into_answer().unwrap(), Answer::from(7));
+    }
+}
@@ -0,0 +1,54 @@
+//! JavaScript bindings, built with `--features wasm` for `wasm32-unknown-unknown`.
+//! The library is only an rlib by default, the cdylib is requested for this build alone:
+//!
+//! ```sh
+//! cargo rustc --lib --release --crate-type cdylib --target wasm32-unknown-unknown --features wasm
+//! wasm-bindgen --target web target/wasm32-unknown-unknown/release/advent_of_code_2015.wasm --out-dir pkg
+//! ```
+
+use std::error::Error;
+
+use wasm_bindgen::prelude::*;
+
+use crate::{
+    input,
+    solution::{self, Answer},
+};
+
+/// The default solution of `part` of `day`, run on `input` as pasted from the puzzle page
+fn answer(day: u32, part: u32, input: &str) -> Result<Answer, Box<dyn Error>> {
+    let solution = solution::find(day, part, None)
+        .ok_or_else(|| format!("No solution for day {} part {}", day, part))?;
+
+    solution.solve(&input::normalize(input))
+}
+
+/// `solve(day, part, input)` in JavaScript, throws an `Error` when there is no answer
+#[wasm_bindgen]
+pub fn solve(day: u32, part: u32, input: &str) -> Result<String, JsError> {
+    answer(day, part, input)
+        .map(|answer| answer.to_string())
+        .map_err(|err| JsError::new(&err.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn answers() {
+        assert_eq!(answer(1, 2, "()())\r\n").unwrap(), Answer::Integer(5));
+        assert_eq!(
+            answer(20, 1, "70").unwrap(),
+            solution::find(20, 1, Some("rayon"))
+                .unwrap()
+                .solve("70")
+                .unwrap()
+        );
+        assert_eq!(
+            answer(25, 2, "").unwrap_err().to_string(),
+            "No solution for day 25 part 2"
+        );
+        assert!(answer(2, 1, "2x3").is_err());
+    }
+}